@@ -8,6 +8,7 @@ use wasm_bindgen::prelude::*;
 use crate::common::MapStringAny;
 use crate::credential::WasmCredential;
 use crate::error::Result;
+use crate::error::WasmResult;
 use crate::jpt::WasmJwpIssued;
 
 #[wasm_bindgen(js_name = DecodedJptCredential)]
@@ -32,6 +33,20 @@ impl WasmDecodedJptCredential {
     }
   }
 
+  /// Returns the non-fatal findings of the validation of the credential.
+  #[wasm_bindgen]
+  pub fn warnings(&self) -> Result<ArrayJptValidationWarning> {
+    JsValue::from_serde(&self.0.warnings)
+      .map(JsValue::unchecked_into)
+      .wasm_result()
+  }
+
+  /// Returns the names of the claims of the JPT in the order of its payloads.
+  #[wasm_bindgen(js_name = "claimNames")]
+  pub fn claim_names(&self) -> Vec<String> {
+    self.0.claim_names()
+  }
+
   // The decoded and verified issued JWP, will be used to construct the presented JWP.
   #[wasm_bindgen(js_name = decodedJwp)]
   pub fn decoded_jwp(&self) -> WasmJwpIssued {
@@ -50,3 +65,17 @@ impl From<WasmDecodedJptCredential> for DecodedJptCredential {
     value.0
   }
 }
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "Array<JptValidationWarning>")]
+  pub type ArrayJptValidationWarning;
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const JPT_VALIDATION_WARNING: &'static str = r#"
+/** A non-fatal finding of the validation of a {@link Jpt}.
+ *
+ * - `NearExpiry`: the credential expires within the `nearExpiryPeriod` of its validation options.
+ */
+type JptValidationWarning = "NearExpiry";"#;
@@ -56,12 +56,25 @@ interface IJptCredentialValidationOptions {
      */
     readonly earliestExpiryDate?: Timestamp;
 
+    /**
+     * Declare that the credential is **not** considered valid if it has no expiration date.
+     *
+     * Default: `false`.
+     */
+    readonly requireExpiry?: boolean;
+
     /**
      * Declare that the credential is **not** considered valid if it was issued later than this {@link Timestamp}.
      * Uses the current datetime during validation if not set. 
      */
     readonly latestIssuanceDate?: Timestamp;
 
+    /**
+     * Tolerated clock skew between issuer and verifier when checking the issuance date.
+     * No skew is tolerated if not set.
+     */
+    readonly issuanceSkew?: Duration;
+
     /**
      * Validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
      */
@@ -77,4 +90,16 @@ interface IJptCredentialValidationOptions {
      * Options which affect the verification of the proof on the credential.
      */
     readonly verificationOptions?: JwpVerificationOptions;
+
+    /**
+     * Declares that a `NearExpiry` warning is raised for a credential expiring within this {@link Duration}
+     * after `earliestExpiryDate`.
+     * No such warning is raised if not set.
+     */
+    readonly nearExpiryPeriod?: Duration;
+
+    /**
+     * Warnings that fail the validation of a credential instead of being returned alongside it.
+     */
+    readonly promotedWarnings?: Array<JptValidationWarning>;
 }"#;
@@ -45,4 +45,9 @@ interface IJwpVerificationOptions {
      * If unset, the `kid` of the JWP is used as the DID URL.
      */
     readonly methodId?: DIDUrl;
+
+    /**
+     * Require the `kid` of the JWP to be present, and to identify the same method as `methodId` if that is set.
+     */
+    readonly requireKid?: boolean;
 }"#;
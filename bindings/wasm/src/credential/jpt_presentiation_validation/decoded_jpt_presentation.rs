@@ -31,6 +31,18 @@ impl WasmDecodedJptPresentation {
     }
   }
 
+  /// Returns the claims disclosed in the JPT, in their JWT claims representation.
+  #[wasm_bindgen(js_name = "disclosedClaims")]
+  pub fn disclosed_claims(&self) -> Result<MapStringAny> {
+    MapStringAny::try_from(self.0.disclosed_claims.clone())
+  }
+
+  /// Returns the names of the claims of the JPT in the order of its payloads.
+  #[wasm_bindgen(js_name = "claimNames")]
+  pub fn claim_names(&self) -> Vec<String> {
+    self.0.claim_names()
+  }
+
   /// Returns the `aud` property parsed from the JWT claims.
   #[wasm_bindgen]
   pub fn aud(&self) -> Option<String> {
@@ -57,6 +57,32 @@ interface IJptPresentationValidationOptions {
      */
     readonly nonce?: string;
 
+    /**
+     * Further nonces that are accepted in the Presentation Protected Header besides `nonce`.
+     */
+    readonly acceptedNonces?: string[];
+
+    /**
+     * If not empty, the `aud` of the Presentation Protected Header must be one of these.
+     */
+    readonly acceptedAudiences?: string[];
+
+    /**
+     * The time at which the challenge (`nonce`) was issued to the holder.
+     */
+    readonly challengeIssuedAt?: Timestamp;
+
+    /**
+     * The presentation is not considered valid if its challenge was issued longer than this ago.
+     * Only checked if `challengeIssuedAt` is set.
+     */
+    readonly challengeMaxAge?: Duration;
+
+    /**
+     * Paths within `credentialSubject`, such as `degree.type`, that the presentation must disclose.
+     */
+    readonly requiredClaims?: string[];
+
     /**
      * Options which affect the verification of the proof on the credential.
      */
@@ -56,6 +56,11 @@ interface IJwtCredentialValidationOptions {
      * Uses the current datetime during validation if not set. */
     readonly earliestExpiryDate?: Timestamp;
 
+    /** Declare that the credential is **not** considered valid if it has no expiration date.
+     *
+     * Default: `false`. */
+    readonly requireExpiry?: boolean;
+
     /** Declare that the credential is **not** considered valid if it was issued later than this {@link Timestamp}.
      * Uses the current datetime during validation if not set. */
     readonly latestIssuanceDate?: Timestamp;
@@ -42,6 +42,18 @@ impl WasmJwsVerificationOptions {
   pub fn set_method_id(&mut self, value: &WasmDIDUrl) {
     self.0.method_id = Some(value.0.clone());
   }
+
+  /// Set whether to accept JWS using the unencoded payload option (`b64` set to `false`).
+  #[wasm_bindgen(js_name = setAllowUnencodedPayload)]
+  pub fn set_allow_unencoded_payload(&mut self, value: bool) {
+    self.0.allow_unencoded_payload = value;
+  }
+
+  /// Accept JWS listing the header parameter `value` in their `crit` header parameter.
+  #[wasm_bindgen(js_name = permitCrit)]
+  pub fn permit_crit(&mut self, value: String) {
+    self.0.permitted_crits.push(value);
+  }
 }
 
 impl_wasm_json!(WasmJwsVerificationOptions, JwsVerificationOptions);
@@ -71,4 +83,16 @@ interface IJwsVerificationOptions {
      * If unset, the `kid` of the JWS is used as the DID Url.
      */
     readonly methodId?: DIDUrl;
+
+    /** Whether to accept JWS using the unencoded payload option (`b64` set to `false`).
+     *
+     * Default: `true`.
+     */
+    readonly allowUnencodedPayload?: boolean;
+
+    /** Header parameters, besides `b64`, that may be listed in the `crit` header parameter of the JWS.
+     *
+     * Default: `[]`.
+     */
+    readonly permittedCrits?: string[];
 }"#;
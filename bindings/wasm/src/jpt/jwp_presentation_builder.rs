@@ -69,12 +69,24 @@ impl WasmSelectiveDisclosurePresentation {
     self.0.conceal_in_subject(&path).wasm_result()
   }
 
+  /// Selectively conceals "credentialSubject" attributes, failing if `path` does not exist or is not a leaf attribute.
+  #[wasm_bindgen(js_name = concealInSubjectStrict)]
+  pub fn conceal_in_subject_strict(&mut self, path: String) -> Result<()> {
+    self.0.conceal_in_subject_strict(&path).wasm_result()
+  }
+
   /// Undiscloses "evidence" attributes.
   #[wasm_bindgen(js_name = concealInEvidence)]
   pub fn conceal_in_evidence(&mut self, path: String) -> Result<()> {
     self.0.conceal_in_evidence(&path).wasm_result()
   }
 
+  /// Undiscloses "evidence" attributes, failing if `path` does not exist or is not a leaf attribute.
+  #[wasm_bindgen(js_name = concealInEvidenceStrict)]
+  pub fn conceal_in_evidence_strict(&mut self, path: String) -> Result<()> {
+    self.0.conceal_in_evidence_strict(&path).wasm_result()
+  }
+
   /// Sets presentation protected header.
   #[wasm_bindgen(js_name = setPresentationHeader)]
   pub fn set_presentation_header(&mut self, header: WasmPresentationProtectedHeader) {
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::ArrayString;
 use crate::common::RecordStringAny;
 use crate::error::Result;
 use crate::error::WasmResult;
@@ -66,18 +67,37 @@ impl WasmJwsSignatureOptions {
     self.0.kid = Some(value);
   }
 
+  /// Replace the value of the `require_resolvable_kid` field.
+  #[wasm_bindgen(js_name = setRequireResolvableKid)]
+  pub fn set_require_resolvable_kid(&mut self, value: bool) {
+    self.0.require_resolvable_kid = value;
+  }
+
   /// Replace the value of the `detached_payload` field.
   #[wasm_bindgen(js_name = setDetachedPayload)]
   pub fn set_detached_payload(&mut self, value: bool) {
     self.0.detached_payload = value;
   }
 
+  /// Replace the value of the `require_utf8_payload` field.
+  #[wasm_bindgen(js_name = setRequireUtf8Payload)]
+  pub fn set_require_utf8_payload(&mut self, value: bool) {
+    self.0.require_utf8_payload = value;
+  }
+
   /// Add additional header parameters.
   #[wasm_bindgen(js_name = setCustomHeaderParameters)]
   pub fn set_custom_header_parameters(&mut self, value: RecordStringAny) -> Result<()> {
     self.0.custom_header_parameters = Some(value.into_serde().wasm_result()?);
     Ok(())
   }
+
+  /// Set the custom header parameters to list in the `crit` header parameter.
+  #[wasm_bindgen(js_name = setCrit)]
+  pub fn set_crit(&mut self, value: ArrayString) -> Result<()> {
+    self.0.crit = Some(value.into_serde().wasm_result()?);
+    Ok(())
+  }
 }
 
 impl_wasm_json!(WasmJwsSignatureOptions, JwsSignatureOptions);
@@ -138,14 +158,39 @@ interface IJwsSignatureOptions {
      */
     readonly kid?: string;
 
+    /**
+     * Whether an explicitly set `kid` must be a DID URL identifying the signing method in the signing document.
+     *
+     * Default: false
+     */
+    readonly requireResolvableKid?: boolean;
+
     /**   /// Whether the payload should be detached from the JWS.
      * 
      * [More Info](https://www.rfc-editor.org/rfc/rfc7515#appendix-F).
      */
     readonly detachedPayload?: boolean
 
+    /**
+     * Whether a non-detached payload must be valid UTF-8.
+     *
+     * Default: false
+     */
+    readonly requireUtf8Payload?: boolean;
+
+    /**
+     * The verification relationship the method used for signing is expected to be part of.
+     */
+    readonly methodScope?: MethodScope;
+
     /**
      * Additional header parameters.
      */
     readonly customHeaderParameters?: Record<string, any>;
+
+    /**
+     * Custom header parameters to list in the `crit` header parameter, signalling that verifiers must understand them.
+     * The parameters must be set in `customHeaderParameters`.
+     */
+    readonly crit?: string[];
 }"#;
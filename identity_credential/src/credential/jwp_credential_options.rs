@@ -12,6 +12,13 @@ pub struct JwpCredentialOptions {
   /// If unset, the kid of the JWK with which the JWP is produced is used.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub kid: Option<String>,
+
+  /// The entry of a `RevocationBitmap2022` service in the issuer's DID document to assign to the credential.
+  ///
+  /// If set, the `credentialStatus` of the credential is replaced by a `RevocationBitmap2022` status referencing this
+  /// entry before the credential is signed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub revocation_bitmap_status: Option<RevocationBitmapEntry>,
 }
 
 impl JwpCredentialOptions {
@@ -25,4 +32,24 @@ impl JwpCredentialOptions {
     self.kid = Some(value.into());
     self
   }
+
+  /// Assigns the credential the entry `index` of the `RevocationBitmap2022` service identified by `service_fragment`
+  /// in the issuer's DID document.
+  pub fn revocation_bitmap_status(mut self, service_fragment: impl Into<String>, index: u32) -> Self {
+    self.revocation_bitmap_status = Some(RevocationBitmapEntry {
+      service_fragment: service_fragment.into(),
+      index,
+    });
+    self
+  }
+}
+
+/// An entry of a `RevocationBitmap2022` service in the issuer's DID document.
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationBitmapEntry {
+  /// The fragment of the `RevocationBitmap2022` service.
+  pub service_fragment: String,
+  /// The index of the credential in the bitmap of the service.
+  pub index: u32,
 }
@@ -34,6 +34,8 @@ pub use self::issuer::Issuer;
 pub use self::jpt::Jpt;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jwp_credential_options::JwpCredentialOptions;
+#[cfg(feature = "jpt-bbs-plus")]
+pub use self::jwp_credential_options::RevocationBitmapEntry;
 pub use self::jws::Jws;
 pub use self::jwt::Jwt;
 pub use self::linked_domain_service::LinkedDomainService;
@@ -87,6 +87,19 @@ impl SelectiveDisclosurePresentation {
     Ok(())
   }
 
+  /// Selectively conceal "credentialSubject" attributes, failing if `path` cannot be concealed.
+  ///
+  /// Unlike [`Self::conceal_in_subject`], which ignores such paths, this fails with
+  /// [`Error::SelectiveDisclosureError`] if `path` does not exist or is not a leaf attribute: concealing an object such
+  /// as `degree` while its nested attributes would remain disclosed is contradictory.
+  pub fn conceal_in_subject_strict(&mut self, path: &str) -> Result<(), Error> {
+    self
+      .jwp_builder
+      .set_undisclosed(&("vc.credentialSubject.".to_owned() + path))
+      .map(|_| ())
+      .map_err(|_| Error::SelectiveDisclosureError)
+  }
+
   /// Undisclose "evidence" attributes.
   /// # Example
   /// ```ignore
@@ -112,6 +125,17 @@ impl SelectiveDisclosurePresentation {
     Ok(())
   }
 
+  /// Undisclose "evidence" attributes, failing if `path` cannot be concealed.
+  ///
+  /// See [`Self::conceal_in_subject_strict`].
+  pub fn conceal_in_evidence_strict(&mut self, path: &str) -> Result<(), Error> {
+    self
+      .jwp_builder
+      .set_undisclosed(&("vc.evidence.".to_owned() + path))
+      .map(|_| ())
+      .map_err(|_| Error::SelectiveDisclosureError)
+  }
+
   /// Set Presentation Protected Header.
   pub fn set_presentation_header(&mut self, ph: PresentationProtectedHeader) {
     self.jwp_builder.set_presentation_protected_header(ph);
@@ -0,0 +1,130 @@
+// Copyright 2020-2024 IOTA Stiftung, Fondazione Links
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwu;
+use rand::RngCore;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::presentation::JwpPresentationOptions;
+use crate::presentation::SelectiveDisclosurePresentation;
+use crate::validator::JptPresentationValidationOptions;
+
+/// The number of random bytes of a generated nonce.
+const NONCE_LENGTH: usize = 32;
+
+/// A verifier's request for a JPT presentation.
+///
+/// It carries the challenge the holder must include as the presentation's `nonce`, and the disclosure frame: the
+/// paths within `credentialSubject` that must be disclosed, and those the holder may conceal.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentationRequest {
+  /// The challenge to be placed in the Presentation Protected Header.
+  pub nonce: String,
+  /// The time at which the challenge was issued.
+  pub issued_at: Timestamp,
+  /// The audience of the presentation (`aud` property in JWP Presentation Header).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub audience: Option<Url>,
+  /// Paths within `credentialSubject` that must be disclosed.
+  #[serde(default)]
+  pub requested_claims: Vec<String>,
+  /// Paths within `credentialSubject` the holder is allowed to conceal.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub concealable_claims: Vec<String>,
+}
+
+impl PresentationRequest {
+  /// Creates a new [`PresentationRequest`] for the given `requested_claims` with a cryptographically random nonce.
+  pub fn new<I, S>(requested_claims: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    let mut nonce = [0; NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    Self {
+      nonce: jwu::encode_b64(nonce),
+      issued_at: Timestamp::now_utc(),
+      audience: None,
+      requested_claims: requested_claims.into_iter().map(Into::into).collect(),
+      concealable_claims: Vec::new(),
+    }
+  }
+
+  /// Sets the audience of the presentation.
+  pub fn audience(mut self, audience: Url) -> Self {
+    self.audience = Some(audience);
+    self
+  }
+
+  /// Allows the holder to conceal the `credentialSubject` attribute at `path`.
+  pub fn concealable_claim(mut self, path: impl Into<String>) -> Self {
+    self.concealable_claims.push(path.into());
+    self
+  }
+
+  /// Returns the [`JwpPresentationOptions`] answering this request.
+  pub fn presentation_options(&self) -> JwpPresentationOptions {
+    JwpPresentationOptions {
+      audience: self.audience.clone(),
+      nonce: Some(self.nonce.clone()),
+    }
+  }
+
+  /// Returns the [`JptPresentationValidationOptions`] checking that a presentation answers this request, i.e. that
+  /// it carries the challenge and audience of this request and discloses the requested claims.
+  pub fn validation_options(&self) -> JptPresentationValidationOptions {
+    let mut options = JptPresentationValidationOptions::default()
+      .nonce(self.nonce.clone())
+      .challenge_issued_at(self.issued_at);
+    options.required_claims = self.requested_claims.clone();
+    if let Some(audience) = &self.audience {
+      options = options.accept_audience(audience.clone());
+    }
+    options
+  }
+
+  /// Conceals all concealable claims of this request in `presentation`.
+  pub fn conceal_in(&self, presentation: &mut SelectiveDisclosurePresentation) -> Result<()> {
+    self
+      .concealable_claims
+      .iter()
+      .try_for_each(|path| presentation.conceal_in_subject_strict(path))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  #[test]
+  fn serialization_round_trip() {
+    let request = PresentationRequest::new(["name", "degree.type"])
+      .audience(Url::parse("https://verifier.example/").unwrap())
+      .concealable_claim("degree.name");
+
+    let json = request.to_json().unwrap();
+    assert_eq!(PresentationRequest::from_json(&json).unwrap(), request);
+
+    let options = request.presentation_options();
+    assert_eq!(options.nonce.as_deref(), Some(request.nonce.as_str()));
+    assert_eq!(options.audience, request.audience);
+  }
+
+  #[test]
+  fn unique_nonce() {
+    let first = PresentationRequest::new(["name"]);
+    let second = PresentationRequest::new(["name"]);
+    assert_ne!(first.nonce, second.nonce);
+    assert_eq!(jwu::decode_b64(&first.nonce).unwrap().len(), NONCE_LENGTH);
+  }
+}
@@ -9,6 +9,8 @@
 mod jwp_presentation_builder;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwp_presentation_options;
+#[cfg(feature = "jpt-bbs-plus")]
+mod jwp_presentation_request;
 mod jwt_presentation_options;
 mod jwt_serialization;
 mod presentation;
@@ -21,6 +23,8 @@ pub use self::presentation::Presentation;
 pub use self::presentation_builder::PresentationBuilder;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_presentation_options::JwpPresentationOptions;
+#[cfg(feature = "jpt-bbs-plus")]
+pub use jwp_presentation_request::PresentationRequest;
 
 #[cfg(feature = "validator")]
 pub(crate) use self::jwt_serialization::PresentationJwtClaims;
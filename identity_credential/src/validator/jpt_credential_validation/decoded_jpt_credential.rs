@@ -2,9 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
 use jsonprooftoken::jwp::issued::JwpIssued;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::credential::Credential;
+use crate::validator::JptValidationWarning;
+use crate::Error;
 
 /// Decoded [`Credential`] from a cryptographically verified JWP.
 #[non_exhaustive]
@@ -16,4 +23,52 @@ pub struct DecodedJptCredential<T = Object> {
   pub custom_claims: Option<Object>,
   /// The decoded and verifier Issued JWP, will be used to construct the Presented JWP
   pub decoded_jwp: JwpIssued,
+  /// The non-fatal findings of the validation of the credential.
+  pub warnings: Vec<JptValidationWarning>,
+}
+
+impl<T> DecodedJptCredential<T> {
+  /// Returns the `cnf` (confirmation) claim of the JPT, if present.
+  ///
+  /// The claim carries the key material the credential is bound to, see
+  /// [RFC 7800](https://www.rfc-editor.org/rfc/rfc7800#section-3.1).
+  pub fn cnf(&self) -> Option<&Object> {
+    self.custom_claims.as_ref()?.get("cnf")?.as_object()
+  }
+
+  /// Serializes the decoded credential to its JSON representation according to the
+  /// [Verifiable Credentials Data model](https://www.w3.org/TR/vc-data-model/), without the JPT wrapper.
+  pub fn to_vc_json(&self) -> identity_core::Result<Value>
+  where
+    T: Serialize,
+  {
+    self.credential.to_json_value()
+  }
+
+  /// Returns the names of the claims of the JPT in the order of its payloads, i.e. the `i`-th payload carries the
+  /// value of the `i`-th claim.
+  pub fn claim_names(&self) -> Vec<String> {
+    self
+      .decoded_jwp
+      .get_claims()
+      .map(|claims| claims.0.clone())
+      .unwrap_or_default()
+  }
+
+  /// Deserializes the custom claims of the JPT into `C`. Returns `None` if the JPT has no custom claims.
+  ///
+  /// # Errors
+  /// Fails if the custom claims cannot be deserialized into `C`.
+  pub fn custom_claims_as<C>(&self) -> Result<Option<C>, Error>
+  where
+    C: DeserializeOwned,
+  {
+    self
+      .custom_claims
+      .clone()
+      .map(|claims| {
+        C::from_json_value(Value::Object(claims)).map_err(|err| Error::JptClaimsSetDeserializationError(err.into()))
+      })
+      .transpose()
+  }
 }
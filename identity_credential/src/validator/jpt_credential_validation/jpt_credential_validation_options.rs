@@ -1,10 +1,14 @@
 // Copyright 2020-2024 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::validator::JptValidationWarning;
 use crate::validator::SubjectHolderRelationship;
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_document::verifiable::JwpVerificationOptions;
+use jsonprooftoken::encoding::SerializationType;
+use jsonprooftoken::jpa::algs::ProofAlgorithm;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -19,12 +23,27 @@ pub struct JptCredentialValidationOptions {
   #[serde(default)]
   pub earliest_expiry_date: Option<Timestamp>,
 
+  /// Declares that the credential is **not** considered valid if it has no expiration date.
+  ///
+  /// Credentials without an expiration date never expire and are considered valid if not set.
+  #[serde(default)]
+  pub require_expiry: bool,
+
   /// Declares that the credential is **not** considered valid if it was issued later than this
   /// [`Timestamp`].
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
 
+  /// Tolerated clock skew between issuer and verifier when checking the issuance date. A credential issued up to
+  /// this [`Duration`] later than `latest_issuance_date` is still considered valid.
+  ///
+  /// A larger skew accepts more credentials from issuers whose clocks run ahead, at the cost of also accepting
+  /// credentials that were deliberately dated into the future by that amount.
+  /// No skew is tolerated if not set.
+  #[serde(default)]
+  pub issuance_skew: Option<Duration>,
+
   /// Validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
   ///
   /// Default: [`StatusCheck::Strict`](crate::validator::StatusCheck::Strict).
@@ -39,6 +58,29 @@ pub struct JptCredentialValidationOptions {
   /// Options which affect the verification of the proof on the credential.
   #[serde(default)]
   pub verification_options: JwpVerificationOptions,
+
+  /// The proof algorithms a credential may be issued with. Credentials using any other algorithm are rejected before
+  /// their proof is verified.
+  /// All algorithms are allowed if not set.
+  #[serde(default)]
+  pub allowed_proof_algs: Option<Vec<ProofAlgorithm>>,
+
+  /// Declares that a [`JptValidationWarning::NearExpiry`] warning is raised for a credential expiring within this
+  /// [`Duration`] after `earliest_expiry_date`.
+  /// No such warning is raised if not set.
+  #[serde(default)]
+  pub near_expiry_period: Option<Duration>,
+
+  /// Warnings that fail the validation of a credential instead of being returned alongside it.
+  #[serde(default)]
+  pub promoted_warnings: Vec<JptValidationWarning>,
+
+  /// The serialization the JPT is expected in. JPTs in any other serialization are rejected with
+  /// [`JwtValidationError::JwpUnsupportedSerialization`](crate::validator::JwtValidationError::JwpUnsupportedSerialization).
+  ///
+  /// Default: [`SerializationType::COMPACT`].
+  #[serde(skip)]
+  pub serialization: Option<SerializationType>,
 }
 
 impl JptCredentialValidationOptions {
@@ -54,6 +96,12 @@ impl JptCredentialValidationOptions {
     self
   }
 
+  /// Declare that the credential is **not** considered valid if it has no expiration date.
+  pub fn require_expiry(mut self, value: bool) -> Self {
+    self.require_expiry = value;
+    self
+  }
+
   /// Declare that the credential is **not** considered valid if it was issued later than this [`Timestamp`].
   /// Uses the current datetime during validation if not set.
   pub fn latest_issuance_date(mut self, timestamp: Timestamp) -> Self {
@@ -61,6 +109,12 @@ impl JptCredentialValidationOptions {
     self
   }
 
+  /// Declare the tolerated clock skew between issuer and verifier when checking the issuance date.
+  pub fn issuance_skew(mut self, skew: Duration) -> Self {
+    self.issuance_skew = Some(skew);
+    self
+  }
+
   /// Sets the validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
   pub fn status_check(mut self, status_check: crate::validator::StatusCheck) -> Self {
     self.status = status_check;
@@ -84,4 +138,28 @@ impl JptCredentialValidationOptions {
     self.verification_options = options;
     self
   }
+
+  /// Declare the proof algorithms a credential may be issued with.
+  pub fn allowed_proof_algs(mut self, algs: impl IntoIterator<Item = ProofAlgorithm>) -> Self {
+    self.allowed_proof_algs = Some(algs.into_iter().collect());
+    self
+  }
+
+  /// Declare that a [`JptValidationWarning::NearExpiry`] warning is raised for a credential expiring within `period`.
+  pub fn near_expiry_period(mut self, period: Duration) -> Self {
+    self.near_expiry_period = Some(period);
+    self
+  }
+
+  /// Declare that `warning` fails the validation of a credential instead of being returned alongside it.
+  pub fn promote_warning(mut self, warning: JptValidationWarning) -> Self {
+    self.promoted_warnings.push(warning);
+    self
+  }
+
+  /// Declare the serialization the JPT is expected in.
+  pub fn serialization(mut self, serialization: SerializationType) -> Self {
+    self.serialization = Some(serialization);
+    self
+  }
 }
@@ -1,13 +1,20 @@
 // Copyright 2020-2024 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
 use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwpVerificationOptions;
+use identity_verification::jwu;
+use identity_verification::MethodScope;
 use jsonprooftoken::encoding::SerializationType;
+use jsonprooftoken::errors::CustomError;
+use jsonprooftoken::jpa::algs::ProofAlgorithm;
 use jsonprooftoken::jpt::claims::JptClaims;
 use jsonprooftoken::jwk::key::Jwk as JwkExt;
 use jsonprooftoken::jwp::issued::JwpIssuedDecoder;
@@ -16,12 +23,22 @@ use super::DecodedJptCredential;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jpt;
+#[cfg(feature = "status-list-2021")]
+use crate::revocation::status_list_2021::StatusList2021Credential;
+use crate::validator::jpt_tracing;
 use crate::validator::jwt_credential_validation::SignerContext;
+use crate::validator::options::ValidationProgress;
 use crate::validator::CompoundCredentialValidationError;
 use crate::validator::FailFast;
 use crate::validator::JptCredentialValidationOptions;
+use crate::validator::JptCredentialValidatorUtils;
+use crate::validator::JptValidationWarning;
 use crate::validator::JwtCredentialValidatorUtils;
 use crate::validator::JwtValidationError;
+use crate::validator::ValidationUnit;
+
+/// A transformation of the claims of a JPT, applied before they are deserialized into a credential.
+type ClaimsTransform<'a> = dyn Fn(&mut Object) -> Result<(), crate::Error> + 'a;
 
 /// A type for decoding and validating [`Credential`]s in JPT format.
 #[non_exhaustive]
@@ -36,6 +53,12 @@ impl JptCredentialValidator {
   /// - the expiration date,
   /// - the issuance date,
   /// - the semantic structure.
+  ///
+  /// The JWT-style `nbf` and `exp` claims must agree with the credential's `issuanceDate` and `expirationDate`
+  /// whenever both are present, otherwise a [`JwtValidationError::CredentialStructure`] error is returned.
+  ///
+  /// Non-fatal findings are returned in [`DecodedJptCredential::warnings`], except for those promoted to errors in
+  /// `options`, which fail with [`JwtValidationError::PromotedWarning`].
   pub fn validate<DOC, T>(
     credential_jpt: &Jpt,
     issuer: &DOC,
@@ -46,62 +69,261 @@ impl JptCredentialValidator {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    // First verify the JWP proof and decode the result into a credential token, then apply all other validations.
-    let credential_token =
-      Self::verify_proof(credential_jpt, issuer, &options.verification_options).map_err(|err| {
-        CompoundCredentialValidationError {
-          validation_errors: [err].into(),
-        }
+    Self::validate_inner(credential_jpt, issuer, options, fail_fast, None, &mut |_, _| {})
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JPT as in [`JptCredentialValidator::validate`], calling
+  /// `on_unit` with the result of each validation unit as soon as it completes.
+  ///
+  /// Units are reported in the order in which they run: [`ValidationUnit::Signature`] for the issuer's proof first,
+  /// followed by the issuance date, expiration date, structure and subject-holder relationship checks. Units that are
+  /// not run because validation already failed, e.g. with [`FailFast::FirstError`], are not reported.
+  ///
+  /// The returned result is the same as that of [`JptCredentialValidator::validate`].
+  pub fn validate_with_progress<DOC, T, F>(
+    credential_jpt: &Jpt,
+    issuer: &DOC,
+    options: &JptCredentialValidationOptions,
+    fail_fast: FailFast,
+    mut on_unit: F,
+  ) -> Result<DecodedJptCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    F: FnMut(ValidationUnit, Result<(), &JwtValidationError>),
+  {
+    Self::validate_inner(credential_jpt, issuer, options, fail_fast, None, &mut on_unit)
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JPT as in [`JptCredentialValidator::validate`], applying
+  /// `claims_transform` to the claims of the JPT before they are deserialized into a credential.
+  ///
+  /// This allows claims stored in a transformed form, e.g. encrypted or encoded fields, to be restored after the
+  /// issuer's proof on the JWP, which covers the claims as issued, has been verified.
+  ///
+  /// # Errors
+  /// Errors of `claims_transform` are returned as [`JwtValidationError::CredentialStructure`], in addition to the
+  /// errors of [`JptCredentialValidator::validate`].
+  pub fn validate_with_claims_transform<DOC, T, F>(
+    credential_jpt: &Jpt,
+    issuer: &DOC,
+    options: &JptCredentialValidationOptions,
+    fail_fast: FailFast,
+    claims_transform: F,
+  ) -> Result<DecodedJptCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    F: Fn(&mut Object) -> Result<(), crate::Error>,
+  {
+    Self::validate_inner(
+      credential_jpt,
+      issuer,
+      options,
+      fail_fast,
+      Some(&claims_transform as &ClaimsTransform<'_>),
+      &mut |_, _| {},
+    )
+  }
+
+  fn validate_inner<DOC, T>(
+    credential_jpt: &Jpt,
+    issuer: &DOC,
+    options: &JptCredentialValidationOptions,
+    fail_fast: FailFast,
+    claims_transform: Option<&ClaimsTransform<'_>>,
+    on_unit: &mut ValidationProgress<'_>,
+  ) -> Result<DecodedJptCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    jpt_tracing::validation("credential", || {
+      // First verify the JWP proof and decode the result into a credential token, then apply all other validations.
+      let proof_validation = Self::verify_proof(
+        credential_jpt,
+        issuer,
+        &options.verification_options,
+        JptCredentialValidatorUtils::expected_serialization(options.serialization.as_ref()),
+        options.allowed_proof_algs.as_deref(),
+        claims_transform,
+      );
+      on_unit(ValidationUnit::Signature, proof_validation.as_ref().map(|_| ()));
+      let credential_token = proof_validation.map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
       })?;
 
-    let credential: &Credential<T> = &credential_token.credential;
+      let credential: &Credential<T> = &credential_token.credential;
+
+      Self::validate_credential::<T>(credential, options, fail_fast, on_unit)?;
+
+      let (promoted, warnings): (Vec<JptValidationWarning>, Vec<JptValidationWarning>) =
+        Self::collect_warnings(credential, options)
+          .into_iter()
+          .partition(|warning| options.promoted_warnings.contains(warning));
+      if !promoted.is_empty() {
+        let promoted_errors = promoted.into_iter().map(JwtValidationError::PromotedWarning);
+        let validation_errors: Vec<JwtValidationError> = match fail_fast {
+          FailFast::FirstError => promoted_errors.take(1).collect(),
+          FailFast::AllErrors => promoted_errors.collect(),
+        };
+        return Err(CompoundCredentialValidationError { validation_errors });
+      }
+
+      Ok(DecodedJptCredential {
+        warnings,
+        ..credential_token
+      })
+    })
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JPT as in [`JptCredentialValidator::validate`], additionally
+  /// checking its `StatusList2021` status against `status_list_credential` according to `options.status`.
+  ///
+  /// The caller is responsible for retrieving the status list credential referenced by the credential's status and
+  /// for validating it.
+  ///
+  /// # Errors
+  /// In addition to the errors of [`JptCredentialValidator::validate`], fails with [`JwtValidationError::Revoked`]
+  /// or [`JwtValidationError::Suspended`] if the credential's entry is set, and with
+  /// [`JwtValidationError::InvalidStatus`] if its status does not reference `status_list_credential`.
+  #[cfg(feature = "status-list-2021")]
+  pub fn validate_with_status_list_2021<DOC, T>(
+    credential_jpt: &Jpt,
+    issuer: &DOC,
+    status_list_credential: &StatusList2021Credential,
+    options: &JptCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJptCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let credential_token = Self::validate(credential_jpt, issuer, options, fail_fast)?;
 
-    Self::validate_credential::<T>(credential, options, fail_fast)?;
+    JwtCredentialValidatorUtils::check_status_with_status_list_2021(
+      &credential_token.credential,
+      status_list_credential,
+      options.status,
+    )
+    .map_err(|err| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    })?;
 
     Ok(credential_token)
   }
 
+  /// Decodes and validates a [`Credential`] issued as a JPT whose payloads have been detached, i.e. whose payloads
+  /// segment is empty.
+  ///
+  /// The base64url decoded `payloads` are attached in the order of the claims in the issuer protected header,
+  /// after which validation proceeds as in [`JptCredentialValidator::validate`]. `credential_jpt` is expected in the
+  /// compact serialization, regardless of `options.serialization`.
+  ///
+  /// # Errors
+  /// Fails if `credential_jpt` does not have detached payloads or if the number of `payloads` does not match the
+  /// number of claims, in addition to the errors of [`JptCredentialValidator::validate`].
+  pub fn validate_detached<DOC, T>(
+    credential_jpt: &Jpt,
+    payloads: &[Vec<u8>],
+    issuer: &DOC,
+    options: &JptCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJptCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let credential_jpt: Jpt =
+      Self::attach_payloads(credential_jpt, payloads).map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+    // Payloads are attached in the compact serialization.
+    let options = JptCredentialValidationOptions {
+      serialization: None,
+      ..options.clone()
+    };
+
+    Self::validate(&credential_jpt, issuer, &options, fail_fast)
+  }
+
+  /// Decodes a [`Credential`] issued as a JPT, only verifying the issuer's proof on the JWP.
+  ///
+  /// None of the semantic validations of [`JptCredentialValidator::validate`] are performed, i.e. it is up to the
+  /// caller to check the expiration date, issuance date, structure and status of the returned credential.
+  /// `credential_jpt` is expected in the compact serialization.
+  pub fn verify_proof_only<DOC, T>(
+    credential_jpt: &Jpt,
+    issuer: &DOC,
+    options: &JwpVerificationOptions,
+  ) -> Result<DecodedJptCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    Self::verify_proof(credential_jpt, issuer, options, SerializationType::COMPACT, None, None)
+  }
+
   pub(crate) fn validate_credential<T>(
     credential: &Credential<T>,
     options: &JptCredentialValidationOptions,
     fail_fast: FailFast,
+    on_unit: &mut ValidationProgress<'_>,
   ) -> Result<(), CompoundCredentialValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
   {
     // Run all single concern Credential validations in turn and fail immediately if `fail_fast` is true.
     let expiry_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_expires_on_or_after(
-        credential,
-        options.earliest_expiry_date.unwrap_or_default(),
-      )
+      jpt_tracing::stage("expiration_date", || {
+        if options.require_expiry {
+          JwtCredentialValidatorUtils::check_has_expiration_date(credential)?;
+        }
+        JwtCredentialValidatorUtils::check_expires_on_or_after(
+          credential,
+          options.earliest_expiry_date.unwrap_or_default(),
+        )
+      })
     });
 
     let issuance_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_issued_on_or_before(
-        credential,
-        options.latest_issuance_date.unwrap_or_default(),
-      )
+      jpt_tracing::stage("issuance_date", || {
+        let latest_issuance_date: Timestamp = options.latest_issuance_date.unwrap_or_default();
+        JwtCredentialValidatorUtils::check_issued_on_or_before(
+          credential,
+          options
+            .issuance_skew
+            .and_then(|skew| latest_issuance_date.checked_add(skew))
+            .unwrap_or(latest_issuance_date),
+        )
+      })
     });
 
-    let structure_validation = std::iter::once_with(|| JwtCredentialValidatorUtils::check_structure(credential));
+    let structure_validation = std::iter::once_with(|| {
+      jpt_tracing::stage("structure", || JwtCredentialValidatorUtils::check_structure(credential))
+    });
 
     let subject_holder_validation = std::iter::once_with(|| {
-      options
-        .subject_holder_relationship
-        .as_ref()
-        .map(|(holder, relationship)| {
-          JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, holder, *relationship)
-        })
-        .unwrap_or(Ok(()))
+      jpt_tracing::stage("subject_holder_relationship", || {
+        options
+          .subject_holder_relationship
+          .as_ref()
+          .map(|(holder, relationship)| {
+            JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, holder, *relationship)
+          })
+          .unwrap_or(Ok(()))
+      })
     });
 
     let validation_units_iter = issuance_date_validation
-      .chain(expiry_date_validation)
-      .chain(structure_validation)
-      .chain(subject_holder_validation);
+      .map(|result| (ValidationUnit::IssuanceDate, result))
+      .chain(expiry_date_validation.map(|result| (ValidationUnit::ExpirationDate, result)))
+      .chain(structure_validation.map(|result| (ValidationUnit::Structure, result)))
+      .chain(subject_holder_validation.map(|result| (ValidationUnit::SubjectHolderRelationship, result)));
 
-    let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
+    let validation_units_error_iter = validation_units_iter
+      .inspect(|(unit, result)| on_unit(*unit, result.as_ref().copied()))
+      .filter_map(|(_, result)| result.err());
     let validation_errors: Vec<JwtValidationError> = match fail_fast {
       FailFast::FirstError => validation_units_error_iter.take(1).collect(),
       FailFast::AllErrors => validation_units_error_iter.collect(),
@@ -114,24 +336,78 @@ impl JptCredentialValidator {
     }
   }
 
+  /// Collects the non-fatal findings on `credential`.
+  fn collect_warnings<T>(
+    credential: &Credential<T>,
+    options: &JptCredentialValidationOptions,
+  ) -> Vec<JptValidationWarning> {
+    let mut warnings: Vec<JptValidationWarning> = Vec::new();
+
+    let near_expiry_date: Option<Timestamp> = options
+      .near_expiry_period
+      .and_then(|period| options.earliest_expiry_date.unwrap_or_default().checked_add(period));
+    if let (Some(near_expiry_date), Some(expiration_date)) = (near_expiry_date, credential.expiration_date) {
+      if expiration_date < near_expiry_date {
+        warnings.push(JptValidationWarning::NearExpiry);
+      }
+    }
+
+    warnings
+  }
+
+  /// Recombines a JPT with detached payloads with the given `payloads`.
+  fn attach_payloads(credential_jpt: &Jpt, payloads: &[Vec<u8>]) -> Result<Jpt, JwtValidationError> {
+    let (header, proof): (&str, &str) = match credential_jpt.as_str().split('.').collect::<Vec<_>>().as_slice() {
+      [header, "", proof] => (*header, *proof),
+      _ => return Err(JwtValidationError::JwpDecodingError(CustomError::SerializationError)),
+    };
+
+    // Every claim in the issuer protected header is matched by exactly one payload.
+    let claims_count: usize = jwu::decode_b64(header)
+      .ok()
+      .and_then(|header| Object::from_json_slice(&header).ok())
+      .and_then(|header| header.get("claims").and_then(Value::as_array).map(Vec::len))
+      .ok_or(JwtValidationError::JwpDecodingError(CustomError::SerializationError))?;
+    if claims_count != payloads.len() {
+      return Err(JwtValidationError::JwpPayloadsMismatch);
+    }
+
+    let payloads: String = payloads.iter().map(jwu::encode_b64).collect::<Vec<_>>().join("~");
+    Ok(Jpt::new(format!("{header}.{payloads}.{proof}")))
+  }
+
   /// Proof verification function
   fn verify_proof<DOC, T>(
     credential: &Jpt,
     issuer: &DOC,
     options: &JwpVerificationOptions,
+    serialization: SerializationType,
+    allowed_proof_algs: Option<&[ProofAlgorithm]>,
+    claims_transform: Option<&ClaimsTransform<'_>>,
   ) -> Result<DecodedJptCredential<T>, JwtValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let decoded = JwpIssuedDecoder::decode(credential.as_str(), SerializationType::COMPACT)
-      .map_err(JwtValidationError::JwpDecodingError)?;
-
-    // If no method_url is set, parse the `kid` to a DID Url which should be the identifier
-    // of a verification method in a trusted issuer's DID document.
-    let method_id: DIDUrl = match &options.method_id {
-      Some(method_id) => method_id.clone(),
-      None => {
+    let decoded: JwpIssuedDecoder = jpt_tracing::stage("decode", || {
+      JptCredentialValidatorUtils::check_serialization(credential, &serialization)?;
+      let decoded =
+        JwpIssuedDecoder::decode(credential.as_str(), serialization).map_err(JwtValidationError::JwpDecodingError)?;
+
+      // Reject disallowed proof algorithms before doing any further work.
+      if let Some(allowed_proof_algs) = allowed_proof_algs {
+        if !allowed_proof_algs.contains(&decoded.get_header().alg()) {
+          return Err(JwtValidationError::JwpProofAlgorithmNotAllowed);
+        }
+      }
+      Ok(decoded)
+    })?;
+
+    let (method_id, public_key): (DIDUrl, JwkExt) = jpt_tracing::stage("issuer_resolution", || {
+      // If no method_url is set, parse the `kid` to a DID Url which should be the identifier
+      // of a verification method in a trusted issuer's DID document. A `kid` that is not a DID Url may instead be the
+      // JWK thumbprint of such a method.
+      let kid_method_id = || -> Result<DIDUrl, JwtValidationError> {
         let kid: &str = decoded
           .get_header()
           .kid()
@@ -142,49 +418,90 @@ impl JptCredentialValidator {
           })?;
 
         // Convert kid to DIDUrl
-        DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
-          source: Some(err.into()),
-          message: "could not parse kid as a DID Url",
-          signer_ctx: SignerContext::Issuer,
-        })?
-      }
-    };
+        DIDUrl::parse(kid).or_else(|err| {
+          Self::method_id_by_thumbprint(issuer.as_ref(), kid, options.method_scope).ok_or_else(|| {
+            JwtValidationError::MethodDataLookupError {
+              source: Some(err.into()),
+              message: "could not parse kid as a DID Url or match it to a JWK thumbprint",
+              signer_ctx: SignerContext::Issuer,
+            }
+          })
+        })
+      };
+      let method_id: DIDUrl = match &options.method_id {
+        Some(method_id) => {
+          // If required, the `kid` must still be present and identify the same method.
+          if options.require_kid && &kid_method_id()? != method_id {
+            return Err(JwtValidationError::MethodDataLookupError {
+              source: None,
+              message: "kid does not match the expected method id",
+              signer_ctx: SignerContext::Issuer,
+            });
+          }
+          method_id.clone()
+        }
+        None => kid_method_id()?,
+      };
 
-    // check issuer
-    let issuer: &CoreDocument = issuer.as_ref();
+      // check issuer
+      let issuer: &CoreDocument = issuer.as_ref();
 
-    if issuer.id() != method_id.did() {
-      return Err(JwtValidationError::DocumentMismatch(SignerContext::Issuer));
-    }
+      if issuer.id() != method_id.did() {
+        return Err(JwtValidationError::DocumentMismatch(SignerContext::Issuer));
+      }
 
-    // Obtain the public key from the issuer's DID document
-    let public_key: JwkExt = issuer
-      .resolve_method(&method_id, options.method_scope)
-      .and_then(|method| method.data().public_key_jwk())
-      .and_then(|k| k.try_into().ok()) //Conversio into jsonprooftoken::Jwk type
-      .ok_or_else(|| JwtValidationError::MethodDataLookupError {
-        source: None,
-        message: "could not extract JWK from a method identified by kid",
-        signer_ctx: SignerContext::Issuer,
-      })?;
+      // Obtain the public key from the issuer's DID document
+      let public_key: JwkExt = issuer
+        .resolve_method(&method_id, options.method_scope)
+        .and_then(|method| method.data().public_key_jwk())
+        .and_then(|k| k.try_into().ok()) //Conversio into jsonprooftoken::Jwk type
+        .ok_or_else(|| JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not extract JWK from a method identified by kid",
+          signer_ctx: SignerContext::Issuer,
+        })?;
+      Ok((method_id, public_key))
+    })?;
 
-    let credential_token = Self::verify_decoded_jwp(decoded, &public_key)?;
+    jpt_tracing::stage("proof_verification", || {
+      let credential_token = Self::verify_decoded_jwp(decoded, &public_key, claims_transform)?;
 
-    // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
-    // returning.
-    let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
-    if &issuer_id != method_id.did() {
-      return Err(JwtValidationError::IdentifierMismatch {
-        signer_ctx: SignerContext::Issuer,
-      });
-    };
-    Ok(credential_token)
+      // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
+      // returning.
+      let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
+      if &issuer_id != method_id.did() {
+        return Err(JwtValidationError::IdentifierMismatch {
+          signer_ctx: SignerContext::Issuer,
+        });
+      };
+      Ok(credential_token)
+    })
+  }
+
+  /// Returns the id of the method of `issuer` whose JWK has the given
+  /// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638) SHA-256 `thumbprint`.
+  pub(crate) fn method_id_by_thumbprint(
+    issuer: &CoreDocument,
+    thumbprint: &str,
+    scope: Option<MethodScope>,
+  ) -> Option<DIDUrl> {
+    issuer
+      .methods(scope)
+      .into_iter()
+      .find(|method| {
+        method
+          .data()
+          .public_key_jwk()
+          .map_or(false, |jwk| jwk.thumbprint_sha256_b64() == thumbprint)
+      })
+      .map(|method| method.id().clone())
   }
 
   /// Verify the decoded issued JWP proof using the given `public_key`.
   fn verify_decoded_jwp<T>(
     decoded: JwpIssuedDecoder,
     public_key: &JwkExt,
+    claims_transform: Option<&ClaimsTransform<'_>>,
   ) -> Result<DecodedJptCredential<T>, JwtValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
@@ -199,10 +516,21 @@ impl JptCredentialValidator {
     })?;
     let payloads = decoded_jwp.get_payloads();
     let jpt_claims = JptClaims::from_claims_and_payloads(claims, payloads);
-    let jpt_claims_json = jpt_claims.to_json_vec().map_err(|err| {
+    let mut jpt_claims_json = jpt_claims.to_json_vec().map_err(|err| {
       JwtValidationError::CredentialStructure(crate::Error::JptClaimsSetDeserializationError(err.into()))
     })?;
 
+    // Restore transformed claims before deserializing them.
+    if let Some(claims_transform) = claims_transform {
+      let mut claims_object = Object::from_json_slice(&jpt_claims_json).map_err(|err| {
+        JwtValidationError::CredentialStructure(crate::Error::JptClaimsSetDeserializationError(err.into()))
+      })?;
+      claims_transform(&mut claims_object).map_err(JwtValidationError::CredentialStructure)?;
+      jpt_claims_json = claims_object.to_json_vec().map_err(|err| {
+        JwtValidationError::CredentialStructure(crate::Error::JptClaimsSetDeserializationError(err.into()))
+      })?;
+    }
+
     // Deserialize the raw claims
     let credential_claims: CredentialJwtClaims<'_, T> = CredentialJwtClaims::from_json_slice(&jpt_claims_json)
       .map_err(|err| {
@@ -220,6 +548,7 @@ impl JptCredentialValidator {
       credential,
       custom_claims,
       decoded_jwp,
+      warnings: Vec::new(),
     })
   }
 }
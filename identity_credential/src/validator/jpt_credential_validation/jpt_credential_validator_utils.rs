@@ -12,6 +12,7 @@ use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_did::DID;
 use jsonprooftoken::encoding::SerializationType;
+use jsonprooftoken::errors::CustomError;
 use jsonprooftoken::jpt::claims::JptClaims;
 use jsonprooftoken::jwp::issued::JwpIssuedDecoder;
 
@@ -81,6 +82,42 @@ impl JptCredentialValidatorUtils {
     })
   }
 
+  /// Returns the serialization a JPT is expected in, defaulting to the compact serialization.
+  pub(crate) fn expected_serialization(serialization: Option<&SerializationType>) -> SerializationType {
+    match serialization {
+      Some(SerializationType::JSON) => SerializationType::JSON,
+      _ => SerializationType::COMPACT,
+    }
+  }
+
+  /// Checks that a JPT is in the `expected` serialization and, if that is the compact serialization, that every
+  /// segment is base64url encoded without padding.
+  ///
+  /// A JPT in the other serialization is reported as such, rather than as an undecodable JPT. Some decoders
+  /// leniently accept padded or standard base64; compact tokens encoded that way are rejected here so that a JPT has
+  /// a single valid encoding.
+  pub(crate) fn check_serialization(jpt: &Jpt, expected: &SerializationType) -> ValidationUnitResult {
+    let expects_json: bool = matches!(expected, SerializationType::JSON);
+    if jpt.as_str().trim_start().starts_with('{') != expects_json {
+      return Err(JwtValidationError::JwpUnsupportedSerialization);
+    }
+    if expects_json {
+      return Ok(());
+    }
+
+    let is_base64url_unpadded = jpt.as_str().split(['.', '~']).all(|segment| {
+      segment
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+    });
+
+    if is_base64url_unpadded {
+      Ok(())
+    } else {
+      Err(JwtValidationError::JwpDecodingError(CustomError::SerializationError))
+    }
+  }
+
   /// Check timeframe interval in credentialStatus with `RevocationTimeframeStatus`.
   pub fn check_timeframes_with_validity_timeframe_2024<T>(
     credential: &Credential<T>,
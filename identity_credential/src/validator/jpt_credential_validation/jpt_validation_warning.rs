@@ -0,0 +1,29 @@
+// Copyright 2020-2024 IOTA Stiftung, Fondazione Links
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A non-fatal finding of JPT credential validation.
+///
+/// Warnings are returned alongside a successfully validated credential in
+/// [`DecodedJptCredential::warnings`](crate::validator::DecodedJptCredential::warnings), unless they are promoted to
+/// errors with [`JptCredentialValidationOptions::promote_warning`](crate::validator::JptCredentialValidationOptions::promote_warning).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JptValidationWarning {
+  /// The credential expires within the period set by
+  /// [`JptCredentialValidationOptions::near_expiry_period`](crate::validator::JptCredentialValidationOptions::near_expiry_period).
+  NearExpiry,
+}
+
+impl Display for JptValidationWarning {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::NearExpiry => f.write_str("the credential is about to expire"),
+    }
+  }
+}
@@ -5,8 +5,10 @@ mod decoded_jpt_credential;
 mod jpt_credential_validation_options;
 mod jpt_credential_validator;
 mod jpt_credential_validator_utils;
+mod jpt_validation_warning;
 
 pub use decoded_jpt_credential::*;
 pub use jpt_credential_validation_options::*;
 pub use jpt_credential_validator::*;
 pub use jpt_credential_validator_utils::*;
+pub use jpt_validation_warning::*;
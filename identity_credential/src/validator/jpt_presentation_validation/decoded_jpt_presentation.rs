@@ -3,7 +3,10 @@
 
 use identity_core::common::Object;
 use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
 use jsonprooftoken::jwp::presented::JwpPresented;
+use serde::Serialize;
 
 use crate::credential::Credential;
 
@@ -17,6 +20,41 @@ pub struct DecodedJptPresentation<T = Object> {
   pub aud: Option<Url>,
   /// The custom claims parsed from the JPT.
   pub custom_claims: Option<Object>,
+  /// The claims disclosed in the JPT, in their JWT claims representation.
+  ///
+  /// Unlike `credential`, which has to fill in required properties the holder may have concealed (such as the
+  /// issuance date), this only contains the claims that were actually disclosed.
+  pub disclosed_claims: Object,
   /// The decoded and verifier Issued JWP, will be used to construct the Presented JWP
   pub decoded_jwp: JwpPresented,
 }
+
+impl<T> DecodedJptPresentation<T> {
+  /// Returns the names of the claims of the JPT in the order of its payloads, i.e. the `i`-th payload carries the
+  /// value of the `i`-th claim. Concealed claims keep their position.
+  pub fn claim_names(&self) -> Vec<String> {
+    self
+      .decoded_jwp
+      .get_claims()
+      .map(|claims| claims.0.clone())
+      .unwrap_or_default()
+  }
+
+  /// Serializes the decoded credential to its JSON representation according to the
+  /// [Verifiable Credentials Data model](https://www.w3.org/TR/vc-data-model/), without the JPT wrapper.
+  ///
+  /// Only disclosed properties are included: an issuance date the holder concealed is omitted rather than
+  /// replaced by the placeholder held by `credential`.
+  pub fn to_vc_json(&self) -> identity_core::Result<Value>
+  where
+    T: Serialize,
+  {
+    let mut vc_json: Value = self.credential.to_json_value()?;
+    if !self.disclosed_claims.contains_key("nbf") && !self.disclosed_claims.contains_key("iat") {
+      if let Some(vc_json) = vc_json.as_object_mut() {
+        vc_json.remove("issuanceDate");
+      }
+    }
+    Ok(vc_json)
+  }
+}
@@ -1,7 +1,11 @@
 // Copyright 2020-2024 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
 use identity_document::verifiable::JwpVerificationOptions;
+use jsonprooftoken::encoding::SerializationType;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -14,9 +18,38 @@ pub struct JptPresentationValidationOptions {
   #[serde(default)]
   pub nonce: Option<String>,
 
+  /// Further nonces that are accepted in the Presentation Protected Header besides `nonce`, e.g. in multi-party
+  /// flows where several verifiers issued a challenge.
+  #[serde(default)]
+  pub accepted_nonces: Vec<String>,
+
+  /// If not empty, the `aud` of the Presentation Protected Header must be one of these.
+  #[serde(default)]
+  pub accepted_audiences: Vec<Url>,
+
+  /// The time at which the challenge (`nonce`) was issued to the holder.
+  #[serde(default)]
+  pub challenge_issued_at: Option<Timestamp>,
+
+  /// Declares that the presentation is **not** considered valid if its challenge was issued longer than this
+  /// [`Duration`] ago. Only checked if `challenge_issued_at` is set.
+  #[serde(default)]
+  pub challenge_max_age: Option<Duration>,
+
+  /// Paths within `credentialSubject`, such as `degree.type`, that the presentation must disclose.
+  #[serde(default)]
+  pub required_claims: Vec<String>,
+
   /// Options which affect the verification of the proof on the credential.
   #[serde(default)]
   pub verification_options: JwpVerificationOptions,
+
+  /// The serialization the presentation JPT is expected in. JPTs in any other serialization are rejected with
+  /// [`JwtValidationError::JwpUnsupportedSerialization`](crate::validator::JwtValidationError::JwpUnsupportedSerialization).
+  ///
+  /// Default: [`SerializationType::COMPACT`].
+  #[serde(skip)]
+  pub serialization: Option<SerializationType>,
 }
 
 impl JptPresentationValidationOptions {
@@ -32,9 +65,46 @@ impl JptPresentationValidationOptions {
     self
   }
 
+  /// Accept `nonce` in the Presentation Protected Header in addition to any other accepted nonce.
+  pub fn accept_nonce(mut self, nonce: impl Into<String>) -> Self {
+    self.accepted_nonces.push(nonce.into());
+    self
+  }
+
+  /// Accept `audience` as the `aud` of the Presentation Protected Header in addition to any other accepted
+  /// audience.
+  pub fn accept_audience(mut self, audience: Url) -> Self {
+    self.accepted_audiences.push(audience);
+    self
+  }
+
+  /// Declare the time at which the challenge was issued to the holder.
+  pub fn challenge_issued_at(mut self, timestamp: Timestamp) -> Self {
+    self.challenge_issued_at = Some(timestamp);
+    self
+  }
+
+  /// Declare the maximum age of the challenge at validation time.
+  pub fn challenge_max_age(mut self, max_age: Duration) -> Self {
+    self.challenge_max_age = Some(max_age);
+    self
+  }
+
+  /// Require the presentation to disclose the `credentialSubject` attribute at `path`.
+  pub fn require_claim(mut self, path: impl Into<String>) -> Self {
+    self.required_claims.push(path.into());
+    self
+  }
+
   /// Set options which affect the verification of the JWP proof.
   pub fn verification_options(mut self, options: JwpVerificationOptions) -> Self {
     self.verification_options = options;
     self
   }
+
+  /// Declare the serialization the presentation JPT is expected in.
+  pub fn serialization(mut self, serialization: SerializationType) -> Self {
+    self.serialization = Some(serialization);
+    self
+  }
 }
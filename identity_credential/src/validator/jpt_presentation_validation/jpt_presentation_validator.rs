@@ -3,6 +3,8 @@
 
 use std::str::FromStr;
 
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
@@ -17,8 +19,12 @@ use jsonprooftoken::jwp::presented::JwpPresentedDecoder;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jpt;
+use crate::validator::jpt_tracing;
 use crate::validator::CompoundCredentialValidationError;
 use crate::validator::FailFast;
+use crate::validator::JptCredentialValidator;
+use crate::validator::JptCredentialValidatorUtils;
+use crate::validator::JptPresentationValidatorUtils;
 use crate::validator::JwtCredentialValidatorUtils;
 use crate::validator::JwtValidationError;
 use crate::validator::SignerContext;
@@ -38,7 +44,8 @@ impl JptPresentationValidator {
   /// - the holder's proof on the JWP,
   /// - the expiration date,
   /// - the issuance date,
-  /// - the semantic structure.
+  /// - the semantic structure,
+  /// - the disclosure of the required claims.
   pub fn validate<DOC, T>(
     presentation_jpt: &Jpt,
     issuer: &DOC,
@@ -49,30 +56,79 @@ impl JptPresentationValidator {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    // First verify the JWP proof and decode the result into a presented credential token, then apply all other
-    // validations.
-    let presented_credential_token =
-      Self::verify_proof(presentation_jpt, issuer, options).map_err(|err| CompoundCredentialValidationError {
-        validation_errors: [err].into(),
-      })?;
+    jpt_tracing::validation("presentation", || {
+      // First verify the JWP proof and decode the result into a presented credential token, then apply all other
+      // validations.
+      let presented_credential_token =
+        Self::verify_proof(presentation_jpt, issuer, options).map_err(|err| CompoundCredentialValidationError {
+          validation_errors: [err].into(),
+        })?;
 
-    let credential: &Credential<T> = &presented_credential_token.credential;
+      Self::validate_presented_credential::<T>(&presented_credential_token, options, fail_fast)?;
 
-    Self::validate_presented_credential::<T>(credential, fail_fast)?;
+      Ok(presented_credential_token)
+    })
+  }
+
+  /// Decodes and validates several Presented [`Credential`]s issued as JPTs, e.g. received together from a wallet.
+  ///
+  /// The issuer of each presentation is selected from `trusted_issuers`. The presentations are validated
+  /// independently of each other and one result is returned per presentation, in the same order. `fail_fast`
+  /// applies to the validation of each presentation.
+  pub fn validate_presentations<DOC, T>(
+    presentation_jpts: &[Jpt],
+    trusted_issuers: &[DOC],
+    options: &JptPresentationValidationOptions,
+    fail_fast: FailFast,
+  ) -> Vec<Result<DecodedJptPresentation<T>, CompoundCredentialValidationError>>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    presentation_jpts
+      .iter()
+      .map(|presentation_jpt| {
+        let issuer: &DOC =
+          JptPresentationValidatorUtils::extract_issuer_from_presented_jpt::<CoreDID>(presentation_jpt)
+            .and_then(|issuer_id| {
+              trusted_issuers
+                .iter()
+                .find(|issuer| issuer.as_ref().id() == &issuer_id)
+                .ok_or(JwtValidationError::DocumentMismatch(SignerContext::Issuer))
+            })
+            .map_err(|err| CompoundCredentialValidationError {
+              validation_errors: [err].into(),
+            })?;
 
-    Ok(presented_credential_token)
+        Self::validate(presentation_jpt, issuer, options, fail_fast)
+      })
+      .collect()
   }
 
   pub(crate) fn validate_presented_credential<T>(
-    credential: &Credential<T>,
+    presented_credential_token: &DecodedJptPresentation<T>,
+    options: &JptPresentationValidationOptions,
     fail_fast: FailFast,
   ) -> Result<(), CompoundCredentialValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
   {
-    let structure_validation = std::iter::once_with(|| JwtCredentialValidatorUtils::check_structure(credential));
+    let structure_validation = std::iter::once_with(|| {
+      jpt_tracing::stage("structure", || {
+        JwtCredentialValidatorUtils::check_structure(&presented_credential_token.credential)
+      })
+    });
+
+    let disclosure_validation = std::iter::once_with(|| {
+      jpt_tracing::stage("disclosure", || {
+        JptPresentationValidatorUtils::check_disclosed_claims(
+          &presented_credential_token.disclosed_claims,
+          &options.required_claims,
+        )
+      })
+    });
 
-    let validation_units_iter = structure_validation;
+    let validation_units_iter = structure_validation.chain(disclosure_validation);
 
     let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
     let validation_errors: Vec<JwtValidationError> = match fail_fast {
@@ -97,23 +153,59 @@ impl JptPresentationValidator {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let decoded: JwpPresentedDecoder =
-      JwpPresentedDecoder::decode(presentation_jpt.as_str(), SerializationType::COMPACT)
+    let decoded: JwpPresentedDecoder = jpt_tracing::stage("decode", || {
+      let serialization: SerializationType =
+        JptCredentialValidatorUtils::expected_serialization(options.serialization.as_ref());
+      JptCredentialValidatorUtils::check_serialization(presentation_jpt, &serialization)?;
+      let decoded: JwpPresentedDecoder = JwpPresentedDecoder::decode(presentation_jpt.as_str(), serialization)
         .map_err(JwtValidationError::JwpDecodingError)?;
 
-    let nonce: Option<&String> = options.nonce.as_ref();
-    // Validate the nonce
-    if decoded.get_presentation_header().nonce() != nonce {
-      return Err(JwtValidationError::JwsDecodingError(
-        identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
-      ));
-    }
+      // Validate the nonce, which must be absent if no nonce is accepted.
+      let mut accepted_nonces = options.nonce.iter().chain(options.accepted_nonces.iter()).peekable();
+      let nonce_valid: bool = match decoded.get_presentation_header().nonce() {
+        Some(nonce) => accepted_nonces.any(|accepted| accepted == nonce),
+        None => accepted_nonces.peek().is_none(),
+      };
+      if !nonce_valid {
+        return Err(JwtValidationError::JwsDecodingError(
+          identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
+        ));
+      }
 
-    // If no method_url is set, parse the `kid` to a DID Url which should be the identifier
-    // of a verification method in a trusted issuer's DID document.
-    let method_id: DIDUrl = match &options.verification_options.method_id {
-      Some(method_id) => method_id.clone(),
-      None => {
+      // Validate the freshness of the challenge.
+      if let (Some(issued_at), Some(max_age)) = (options.challenge_issued_at, options.challenge_max_age) {
+        let challenge_fresh: bool = issued_at
+          .checked_add(max_age)
+          .map_or(false, |expires_at| expires_at >= Timestamp::now_utc());
+        if !challenge_fresh {
+          return Err(JwtValidationError::JwsDecodingError(
+            identity_verification::jose::error::Error::InvalidParam("expired challenge"),
+          ));
+        }
+      }
+
+      // Validate the audience
+      if !options.accepted_audiences.is_empty() {
+        let audience_valid: bool = decoded.get_presentation_header().aud().map_or(false, |aud| {
+          options
+            .accepted_audiences
+            .iter()
+            .any(|accepted| accepted.as_str() == aud)
+        });
+        if !audience_valid {
+          return Err(JwtValidationError::JwsDecodingError(
+            identity_verification::jose::error::Error::InvalidParam("invalid audience value"),
+          ));
+        }
+      }
+      Ok(decoded)
+    })?;
+
+    let (method_id, public_key): (DIDUrl, JwkExt) = jpt_tracing::stage("issuer_resolution", || {
+      // If no method_url is set, parse the `kid` to a DID Url which should be the identifier
+      // of a verification method in a trusted issuer's DID document. A `kid` that is not a DID Url may instead be the
+      // JWK thumbprint of such a method.
+      let kid_method_id = || -> Result<DIDUrl, JwtValidationError> {
         let kid: &str = decoded
           .get_issuer_header()
           .kid()
@@ -124,43 +216,67 @@ impl JptPresentationValidator {
           })?;
 
         // Convert kid to DIDUrl
-        DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
-          source: Some(err.into()),
-          message: "could not parse kid as a DID Url",
-          signer_ctx: SignerContext::Issuer,
-        })?
-      }
-    };
+        DIDUrl::parse(kid).or_else(|err| {
+          JptCredentialValidator::method_id_by_thumbprint(
+            issuer.as_ref(),
+            kid,
+            options.verification_options.method_scope,
+          )
+          .ok_or_else(|| JwtValidationError::MethodDataLookupError {
+            source: Some(err.into()),
+            message: "could not parse kid as a DID Url or match it to a JWK thumbprint",
+            signer_ctx: SignerContext::Issuer,
+          })
+        })
+      };
+      let method_id: DIDUrl = match &options.verification_options.method_id {
+        Some(method_id) => {
+          // If required, the `kid` must still be present and identify the same method.
+          if options.verification_options.require_kid && &kid_method_id()? != method_id {
+            return Err(JwtValidationError::MethodDataLookupError {
+              source: None,
+              message: "kid does not match the expected method id",
+              signer_ctx: SignerContext::Issuer,
+            });
+          }
+          method_id.clone()
+        }
+        None => kid_method_id()?,
+      };
 
-    // check issuer
-    let issuer: &CoreDocument = issuer.as_ref();
+      // check issuer
+      let issuer: &CoreDocument = issuer.as_ref();
 
-    if issuer.id() != method_id.did() {
-      return Err(JwtValidationError::DocumentMismatch(SignerContext::Issuer));
-    }
+      if issuer.id() != method_id.did() {
+        return Err(JwtValidationError::DocumentMismatch(SignerContext::Issuer));
+      }
 
-    // Obtain the public key from the issuer's DID document
-    let public_key: JwkExt = issuer
-      .resolve_method(&method_id, options.verification_options.method_scope)
-      .and_then(|method| method.data().public_key_jwk())
-      .and_then(|k| k.try_into().ok()) //Conversio into jsonprooftoken::Jwk type
-      .ok_or_else(|| JwtValidationError::MethodDataLookupError {
-        source: None,
-        message: "could not extract JWK from a method identified by kid",
-        signer_ctx: SignerContext::Issuer,
-      })?;
+      // Obtain the public key from the issuer's DID document
+      let public_key: JwkExt = issuer
+        .resolve_method(&method_id, options.verification_options.method_scope)
+        .and_then(|method| method.data().public_key_jwk())
+        .and_then(|k| k.try_into().ok()) //Conversio into jsonprooftoken::Jwk type
+        .ok_or_else(|| JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not extract JWK from a method identified by kid",
+          signer_ctx: SignerContext::Issuer,
+        })?;
+      Ok((method_id, public_key))
+    })?;
 
-    let credential_token = Self::verify_decoded_jwp(decoded, &public_key)?;
+    jpt_tracing::stage("proof_verification", || {
+      let credential_token = Self::verify_decoded_jwp(decoded, &public_key)?;
 
-    // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
-    // returning.
-    let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
-    if &issuer_id != method_id.did() {
-      return Err(JwtValidationError::IdentifierMismatch {
-        signer_ctx: SignerContext::Issuer,
-      });
-    };
-    Ok(credential_token)
+      // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
+      // returning.
+      let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
+      if &issuer_id != method_id.did() {
+        return Err(JwtValidationError::IdentifierMismatch {
+          signer_ctx: SignerContext::Issuer,
+        });
+      };
+      Ok(credential_token)
+    })
   }
 
   /// Verify the decoded presented JWP proof using the given `public_key`.
@@ -181,6 +297,7 @@ impl JptPresentationValidator {
     })?;
     let payloads = decoded_jwp.get_payloads();
     let mut jpt_claims = JptClaims::from_claims_and_payloads(claims, payloads);
+    let nbf_disclosed: bool = jpt_claims.nbf.is_some();
     // if not set the deserializatioon will throw an error since even the iat is not set, so we set this to 0
     jpt_claims.nbf.map_or_else(
       || {
@@ -193,6 +310,14 @@ impl JptPresentationValidator {
       JwtValidationError::CredentialStructure(crate::Error::JptClaimsSetDeserializationError(err.into()))
     })?;
 
+    // Keep a view of the disclosed claims only, without the placeholder set above.
+    let mut disclosed_claims: Object = Object::from_json_slice(&jpt_claims_json).map_err(|err| {
+      JwtValidationError::CredentialStructure(crate::Error::JptClaimsSetDeserializationError(err.into()))
+    })?;
+    if !nbf_disclosed {
+      disclosed_claims.remove("nbf");
+    }
+
     // Deserialize the raw claims
     let credential_claims: CredentialJwtClaims<'_, T> = CredentialJwtClaims::from_json_slice(&jpt_claims_json)
       .map_err(|err| {
@@ -220,6 +345,7 @@ impl JptPresentationValidator {
       credential,
       aud,
       custom_claims,
+      disclosed_claims,
       decoded_jwp,
     })
   }
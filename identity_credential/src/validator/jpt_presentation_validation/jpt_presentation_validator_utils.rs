@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
+use identity_core::common::Value;
 use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_did::DID;
@@ -96,4 +97,21 @@ impl JptPresentationValidatorUtils {
       }
     }
   }
+
+  /// Checks that each of the `paths` within `credentialSubject` is disclosed in `disclosed_claims`, the claims of a
+  /// presentation in their JWT claims representation (see
+  /// [`DecodedJptPresentation::disclosed_claims`](crate::validator::DecodedJptPresentation::disclosed_claims)).
+  pub fn check_disclosed_claims(disclosed_claims: &Object, paths: &[String]) -> ValidationUnitResult {
+    let subject: Option<&Value> = disclosed_claims.get("vc").and_then(|vc| vc.get("credentialSubject"));
+
+    paths
+      .iter()
+      .find(|path| {
+        path
+          .split('.')
+          .fold(subject, |value, segment| value.and_then(|value| value.get(segment)))
+          .is_none()
+      })
+      .map_or(Ok(()), |path| Err(JwtValidationError::UndisclosedClaim(path.clone())))
+  }
 }
@@ -0,0 +1,35 @@
+// Copyright 2020-2024 IOTA Stiftung, Fondazione Links
+// SPDX-License-Identifier: Apache-2.0
+
+//! `tracing` instrumentation of the JPT validators.
+//!
+//! With the `tracing` feature, each validation is wrapped in a `jpt_validation` span and each of its stages in a
+//! nested `jpt_validation_stage` span recording the outcome of the stage in its `success` field. Their durations are
+//! measured by the installed subscriber. Without the feature, these functions compile down to calling the stage.
+
+/// Runs the validation `f` of a JPT of the given `kind` inside a span.
+#[cfg(feature = "tracing")]
+pub(crate) fn validation<R>(kind: &'static str, f: impl FnOnce() -> R) -> R {
+  tracing::info_span!("jpt_validation", kind).in_scope(f)
+}
+
+#[cfg(not(feature = "tracing"))]
+#[inline(always)]
+pub(crate) fn validation<R>(_kind: &'static str, f: impl FnOnce() -> R) -> R {
+  f()
+}
+
+/// Runs the validation stage `f` inside a span identifying the `stage` and recording whether it succeeded.
+#[cfg(feature = "tracing")]
+pub(crate) fn stage<R, E>(stage: &'static str, f: impl FnOnce() -> Result<R, E>) -> Result<R, E> {
+  let span = tracing::info_span!("jpt_validation_stage", stage, success = tracing::field::Empty);
+  let result = span.in_scope(f);
+  span.record("success", result.is_ok());
+  result
+}
+
+#[cfg(not(feature = "tracing"))]
+#[inline(always)]
+pub(crate) fn stage<R, E>(_stage: &'static str, f: impl FnOnce() -> Result<R, E>) -> Result<R, E> {
+  f()
+}
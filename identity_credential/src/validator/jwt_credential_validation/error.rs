@@ -43,6 +43,9 @@ pub enum JwtValidationError {
   /// Indicates that the expiration date of the credential or presentation is not considered valid.
   #[error("the expiration date is in the past or earlier than required")]
   ExpirationDate,
+  /// Indicates that the credential has no expiration date, although one is required.
+  #[error("the credential has no expiration date")]
+  MissingExpirationDate,
   /// Indicates that the issuance date of the credential or presentation is not considered valid.
   #[error("issuance date is in the future or later than required")]
   IssuanceDate,
@@ -78,6 +81,9 @@ pub enum JwtValidationError {
 
   /// Indicates that the structure of the [Credential](crate::credential::Credential) is not semantically
   /// correct.
+  ///
+  /// For JWPs this is reported after a successful proof verification, meaning the issuer did sign claims that do
+  /// not form a valid credential.
   #[error("the credential's structure is not semantically correct")]
   CredentialStructure(#[source] crate::Error),
   /// Indicates that the structure of the [Presentation](crate::presentation::Presentation) is not
@@ -109,10 +115,36 @@ pub enum JwtValidationError {
   #[error("timeframe interval not valid")]
   OutsideTimeframe,
   /// Indicates that the JWP representation of an issued credential or presentation could not be decoded.
+  ///
+  /// This points to malformed input rather than to a forgery, as no proof has been checked yet.
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("could not decode jwp")]
   JwpDecodingError(#[source] jsonprooftoken::errors::CustomError),
-  /// Indicates that the verification of the JWP has failed
+  /// Indicates that a JWP is not in the serialization the validation options expect, e.g. a JWP in JSON
+  /// serialization validated as a compact JWP.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("jwp is not in the expected serialization")]
+  JwpUnsupportedSerialization,
+  /// Indicates that a presentation conceals a claim the verifier requires to be disclosed.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("the required claim `{0}` is not disclosed")]
+  UndisclosedClaim(String),
+  /// Indicates that the number of detached JWP payloads does not match the number of claims.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("the number of detached jwp payloads does not match the number of claims")]
+  JwpPayloadsMismatch,
+  /// Indicates that the JWP uses a proof algorithm which is not allowed by the validation options.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("the jwp proof algorithm is not allowed")]
+  JwpProofAlgorithmNotAllowed,
+  /// Indicates a validation warning that was promoted to an error by the validation options.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("validation warning treated as an error: {0}")]
+  PromotedWarning(crate::validator::JptValidationWarning),
+  /// Indicates that the verification of the JWP has failed.
+  ///
+  /// This is security relevant: the JWP was well-formed but its proof does not match the issuer's key, e.g.
+  /// because the payloads or headers have been tampered with.
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("could not verify jwp")]
   JwpProofVerificationError(#[source] jsonprooftoken::errors::CustomError),
@@ -20,6 +20,12 @@ pub struct JwtCredentialValidationOptions {
   #[serde(default)]
   pub earliest_expiry_date: Option<Timestamp>,
 
+  /// Declares that the credential is **not** considered valid if it has no expiration date.
+  ///
+  /// Credentials without an expiration date never expire and are considered valid if not set.
+  #[serde(default)]
+  pub require_expiry: bool,
+
   /// Declares that the credential is **not** considered valid if it was issued later than this
   /// [`Timestamp`].
   /// Uses the current datetime during validation if not set.
@@ -55,6 +61,12 @@ impl JwtCredentialValidationOptions {
     self
   }
 
+  /// Declare that the credential is **not** considered valid if it has no expiration date.
+  pub fn require_expiry(mut self, value: bool) -> Self {
+    self.require_expiry = value;
+    self
+  }
+
   /// Declare that the credential is **not** considered valid if it was issued later than this [`Timestamp`].
   /// Uses the current datetime during validation if not set.
   pub fn latest_issuance_date(mut self, timestamp: Timestamp) -> Self {
@@ -21,7 +21,10 @@ use super::SignerContext;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
+use crate::presentation::Presentation;
+use crate::validator::options::ValidationProgress;
 use crate::validator::FailFast;
+use crate::validator::ValidationUnit;
 
 /// A type for decoding and validating [`Credential`]s.
 #[non_exhaustive]
@@ -68,24 +71,89 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let credential_token = self
-      .verify_signature(
-        credential_jwt,
-        std::slice::from_ref(issuer.as_ref()),
-        &options.verification_options,
-      )
-      .map_err(|err| CompoundCredentialValidationError {
-        validation_errors: [err].into(),
-      })?;
+    self.validate_with_progress(credential_jwt, issuer, options, fail_fast, |_, _| {})
+  }
 
-    Self::validate_decoded_credential::<CoreDocument, T>(
+  /// Decodes and validates a [`Credential`] issued as a JWT as in [`Self::validate`], calling `on_unit` with the
+  /// result of each validation unit as soon as it completes.
+  ///
+  /// Units are reported in the order in which they run: [`ValidationUnit::Signature`] first, followed by the
+  /// issuance date, expiration date, structure, subject-holder relationship and status checks. Units that are not run
+  /// because validation already failed, e.g. with [`FailFast::FirstError`], are not reported.
+  ///
+  /// The returned result is the same as that of [`Self::validate`].
+  pub fn validate_with_progress<DOC, T, F>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+    mut on_unit: F,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    F: FnMut(ValidationUnit, Result<(), &JwtValidationError>),
+  {
+    let signature_validation = self.verify_signature(
+      credential_jwt,
+      std::slice::from_ref(issuer.as_ref()),
+      &options.verification_options,
+    );
+    on_unit(ValidationUnit::Signature, signature_validation.as_ref().map(|_| ()));
+    let credential_token = signature_validation.map_err(|err| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    })?;
+
+    Self::validate_decoded_credential_with_progress::<CoreDocument, T>(
       credential_token,
       std::slice::from_ref(issuer.as_ref()),
       options,
       fail_fast,
+      &mut on_unit,
     )
   }
 
+  /// Decodes and validates every credential embedded in `presentation` against the DID Documents of
+  /// `trusted_issuers`.
+  ///
+  /// Validating a presentation with [`JwtPresentationValidator`](crate::validator::JwtPresentationValidator) only
+  /// verifies the holder's signature; a holder could still bundle a credential of an issuer the verifier does not
+  /// trust. Each credential is validated as in [`Self::validate`], with its issuer looked up in `trusted_issuers`.
+  ///
+  /// One result is returned per credential, in the order in which the credentials appear in the presentation, so
+  /// that a failing credential can be identified by its index.
+  ///
+  /// # Errors
+  /// A credential whose issuer is not among `trusted_issuers` fails with
+  /// [`JwtValidationError::DocumentMismatch`]. Any other failed validation of a credential is reported as in
+  /// [`Self::validate`].
+  pub fn validate_presented_credentials<DOC, T, U>(
+    &self,
+    presentation: &Presentation<Jwt, U>,
+    trusted_issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Vec<Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    presentation
+      .verifiable_credential
+      .iter()
+      .map(|credential_jwt| {
+        let credential_token = self
+          .verify_signature(credential_jwt, trusted_issuers, &options.verification_options)
+          .map_err(|err| CompoundCredentialValidationError {
+            validation_errors: [err].into(),
+          })?;
+
+        Self::validate_decoded_credential(credential_token, trusted_issuers, options, fail_fast)
+      })
+      .collect()
+  }
+
   /// Decode and verify the JWS signature of a [`Credential`] issued as a JWT using the DID Document of a trusted
   /// issuer.
   ///
@@ -124,6 +192,20 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     options: &JwtCredentialValidationOptions,
     fail_fast: FailFast,
   ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    Self::validate_decoded_credential_with_progress(credential_token, issuers, options, fail_fast, &mut |_, _| {})
+  }
+
+  fn validate_decoded_credential_with_progress<DOC, T>(
+    credential_token: DecodedJwtCredential<T>,
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+    on_unit: &mut ValidationProgress<'_>,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
@@ -132,6 +214,9 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     // Run all single concern Credential validations in turn and fail immediately if `fail_fast` is true.
 
     let expiry_date_validation = std::iter::once_with(|| {
+      if options.require_expiry {
+        JwtCredentialValidatorUtils::check_has_expiration_date(credential)?;
+      }
       JwtCredentialValidatorUtils::check_expires_on_or_after(
         &credential_token.credential,
         options.earliest_expiry_date.unwrap_or_default(),
@@ -158,18 +243,21 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     });
 
     let validation_units_iter = issuance_date_validation
-      .chain(expiry_date_validation)
-      .chain(structure_validation)
-      .chain(subject_holder_validation);
+      .map(|result| (ValidationUnit::IssuanceDate, result))
+      .chain(expiry_date_validation.map(|result| (ValidationUnit::ExpirationDate, result)))
+      .chain(structure_validation.map(|result| (ValidationUnit::Structure, result)))
+      .chain(subject_holder_validation.map(|result| (ValidationUnit::SubjectHolderRelationship, result)));
 
     #[cfg(feature = "revocation-bitmap")]
     let validation_units_iter = {
       let revocation_validation =
         std::iter::once_with(|| JwtCredentialValidatorUtils::check_status(credential, issuers, options.status));
-      validation_units_iter.chain(revocation_validation)
+      validation_units_iter.chain(revocation_validation.map(|result| (ValidationUnit::Status, result)))
     };
 
-    let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
+    let validation_units_error_iter = validation_units_iter
+      .inspect(|(unit, result)| on_unit(*unit, result.as_ref().copied()))
+      .filter_map(|(_, result)| result.err());
     let validation_errors: Vec<JwtValidationError> = match fail_fast {
       FailFast::FirstError => validation_units_error_iter.take(1).collect(),
       FailFast::AllErrors => validation_units_error_iter.collect(),
@@ -38,6 +38,9 @@ impl JwtCredentialValidatorUtils {
   }
 
   /// Validate that the [`Credential`] expires on or after the specified [`Timestamp`].
+  ///
+  /// A credential without an expiration date never expires and always passes this check, see
+  /// [`Self::check_has_expiration_date`] to reject such credentials.
   pub fn check_expires_on_or_after<T>(credential: &Credential<T>, timestamp: Timestamp) -> ValidationUnitResult {
     let expiration_date: Option<Timestamp> = credential.expiration_date;
     (expiration_date.is_none() || expiration_date >= Some(timestamp))
@@ -45,6 +48,15 @@ impl JwtCredentialValidatorUtils {
       .ok_or(JwtValidationError::ExpirationDate)
   }
 
+  /// Validate that the [`Credential`] has an expiration date.
+  pub fn check_has_expiration_date<T>(credential: &Credential<T>) -> ValidationUnitResult {
+    credential
+      .expiration_date
+      .is_some()
+      .then_some(())
+      .ok_or(JwtValidationError::MissingExpirationDate)
+  }
+
   /// Validate that the [`Credential`] is issued on or before the specified [`Timestamp`].
   pub fn check_issued_on_or_before<T>(credential: &Credential<T>, timestamp: Timestamp) -> ValidationUnitResult {
     (credential.issuance_date <= timestamp)
@@ -48,7 +48,11 @@ where
   ///
   /// * This method does NOT validate the constituent credentials and therefore also not the relationship between the
   /// credentials' subjects and the presentation holder. This can be done with
-  /// [`JwtCredentialValidationOptions`](crate::validator::JwtCredentialValidationOptions).
+  /// [`JwtCredentialValidationOptions`](crate::validator::JwtCredentialValidationOptions) and
+  /// [`JwtCredentialValidator::validate_presented_credentials`](crate::validator::JwtCredentialValidator::validate_presented_credentials).
+  /// Conversely, verifiers that already validated the credentials only need this method to verify the holder binding
+  /// of a fresh presentation, including its nonce.
+  /// The same holds for presentations disclosing no credentials at all, which only prove control of the holder's DID.
   /// * The lack of an error returned from this method is in of itself not enough to conclude that the presentation can
   /// be trusted. This section contains more information on additional checks that should be carried out before and
   /// after calling this method.
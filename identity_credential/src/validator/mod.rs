@@ -12,6 +12,7 @@ pub use self::jwt_presentation_validation::*;
 pub use self::options::FailFast;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
+pub use self::options::ValidationUnit;
 #[cfg(feature = "sd-jwt")]
 pub use self::sd_jwt::*;
 
@@ -19,6 +20,8 @@ pub use self::sd_jwt::*;
 mod jpt_credential_validation;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_presentation_validation;
+#[cfg(feature = "jpt-bbs-plus")]
+mod jpt_tracing;
 mod jwt_credential_validation;
 mod jwt_presentation_validation;
 mod options;
@@ -4,6 +4,8 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::JwtValidationError;
+
 /// Controls validation behaviour when checking whether or not a credential has been revoked by its
 /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
@@ -60,3 +62,24 @@ pub enum FailFast {
   /// Return after the first error occurs.
   FirstError,
 }
+
+/// A single concern checked while validating a credential, as reported to validation progress callbacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ValidationUnit {
+  /// The issuer's signature on a JWT or proof on a JPT.
+  Signature,
+  /// The issuance date of the credential.
+  IssuanceDate,
+  /// The expiration date of the credential.
+  ExpirationDate,
+  /// The semantic structure of the credential.
+  Structure,
+  /// The relationship between the credential subjects and the holder.
+  SubjectHolderRelationship,
+  /// The status of the credential.
+  Status,
+}
+
+/// A callback notified of the result of each [`ValidationUnit`] as soon as it completes.
+pub(crate) type ValidationProgress<'a> = dyn FnMut(ValidationUnit, Result<(), &JwtValidationError>) + 'a;
@@ -950,7 +950,7 @@ impl CoreDocument {
     options: &JwsVerificationOptions,
   ) -> Result<DecodedJws<'jws>> {
     let validation_item = Decoder::new()
-      .decode_compact_serialization(jws.as_bytes(), detached_payload)
+      .decode_compact_serialization_with_crits(jws.as_bytes(), detached_payload, &options.permitted_crits)
       .map_err(Error::JwsVerificationError)?;
 
     let nonce: Option<&str> = options.nonce.as_deref();
@@ -961,6 +961,18 @@ impl CoreDocument {
       ));
     }
 
+    // Reject unencoded payloads if required, regardless of the signature's validity.
+    if !options.allow_unencoded_payload
+      && validation_item
+        .protected_header()
+        .and_then(|header| header.b64())
+        .map_or(false, |b64| !b64)
+    {
+      return Err(Error::JwsVerificationError(
+        identity_verification::jose::error::Error::InvalidParam("unencoded payload is not allowed"),
+      ));
+    }
+
     let method_url_query: DIDUrlQuery<'_> = match &options.method_id {
       Some(method_id) => method_id.into(),
       None => validation_item
@@ -14,6 +14,9 @@ pub struct JwpVerificationOptions {
   /// The DID URl of the method, whose JWK should be used to verify the JWP.
   /// If unset, the `kid` of the JWP is used as the DID Url.
   pub method_id: Option<DIDUrl>,
+  /// Require the `kid` of the JWP to be present, and to identify the same method as `method_id` if that is set.
+  #[serde(default)]
+  pub require_kid: bool,
 }
 
 impl JwpVerificationOptions {
@@ -33,4 +36,10 @@ impl JwpVerificationOptions {
     self.method_id = Some(value);
     self
   }
+
+  /// Require the `kid` of the JWP to be present, and to identify the same method as `method_id` if that is set.
+  pub fn require_kid(mut self, value: bool) -> Self {
+    self.require_kid = value;
+    self
+  }
 }
@@ -7,8 +7,8 @@ use identity_verification::MethodScope;
 /// Holds additional options for verifying a JWS with
 /// [`CoreDocument::verify_jws`](crate::document::CoreDocument::verify_jws()).
 #[non_exhaustive]
-#[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
 pub struct JwsVerificationOptions {
   /// Verify that the nonce set in the protected header matches this value.
   ///
@@ -19,6 +19,30 @@ pub struct JwsVerificationOptions {
   /// The DID URl of the method, whose JWK should be used to verify the JWS.
   /// If unset, the `kid` of the JWS is used as the DID Url.
   pub method_id: Option<DIDUrl>,
+  /// Whether to accept JWS using the unencoded payload option (`b64` set to `false`).
+  ///
+  /// Default: `true`.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc7797)
+  pub allow_unencoded_payload: bool,
+  /// Header parameters, besides `b64`, that may be listed in the `crit` header parameter of the JWS.
+  ///
+  /// A JWS listing any other parameter in `crit` is rejected.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  pub permitted_crits: Vec<String>,
+}
+
+impl Default for JwsVerificationOptions {
+  fn default() -> Self {
+    Self {
+      nonce: None,
+      method_scope: None,
+      method_id: None,
+      allow_unencoded_payload: true,
+      permitted_crits: Vec::new(),
+    }
+  }
 }
 
 impl JwsVerificationOptions {
@@ -44,4 +68,16 @@ impl JwsVerificationOptions {
     self.method_id = Some(value);
     self
   }
+
+  /// Whether to accept JWS using the unencoded payload option (`b64` set to `false`).
+  pub fn allow_unencoded_payload(mut self, value: bool) -> Self {
+    self.allow_unencoded_payload = value;
+    self
+  }
+
+  /// Accept JWS listing the header parameter `value` in their `crit` header parameter.
+  pub fn permit_crit(mut self, value: impl Into<String>) -> Self {
+    self.permitted_crits.push(value.into());
+    self
+  }
 }
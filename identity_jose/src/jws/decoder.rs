@@ -228,6 +228,19 @@ impl Decoder {
     &self,
     jws_bytes: &'b [u8],
     detached_payload: Option<&'b [u8]>,
+  ) -> Result<JwsValidationItem<'b>> {
+    self.decode_compact_serialization_with_crits(jws_bytes, detached_payload, &[])
+  }
+
+  /// Similar to [`Self::decode_compact_serialization`], but additionally accepts the header parameters in
+  /// `permitted_crits` in the "crit" header parameter. Only "b64" is accepted otherwise.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  pub fn decode_compact_serialization_with_crits<'b>(
+    &self,
+    jws_bytes: &'b [u8],
+    detached_payload: Option<&'b [u8]>,
+    permitted_crits: &[String],
   ) -> Result<JwsValidationItem<'b>> {
     let mut segments = jws_bytes.split(|byte| *byte == b'.');
 
@@ -245,7 +258,7 @@ impl Decoder {
 
     let payload = Self::expand_payload(detached_payload, Some(payload))?;
 
-    self.decode_signature(payload, signature)
+    self.decode_signature(payload, signature, permitted_crits)
   }
 
   /// Decode a JWS encoded with the [flattened JWS JSON serialization format](https://www.rfc-editor.org/rfc/rfc7515#section-7.2.2).
@@ -258,17 +271,31 @@ impl Decoder {
     &self,
     jws_bytes: &'b [u8],
     detached_payload: Option<&'b [u8]>,
+  ) -> Result<JwsValidationItem<'b>> {
+    self.decode_flattened_serialization_with_crits(jws_bytes, detached_payload, &[])
+  }
+
+  /// Similar to [`Self::decode_flattened_serialization`], but additionally accepts the header parameters in
+  /// `permitted_crits` in the "crit" header parameter. Only "b64" is accepted otherwise.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  pub fn decode_flattened_serialization_with_crits<'b>(
+    &self,
+    jws_bytes: &'b [u8],
+    detached_payload: Option<&'b [u8]>,
+    permitted_crits: &[String],
   ) -> Result<JwsValidationItem<'b>> {
     let data: Flatten<'_> = serde_json::from_slice(jws_bytes).map_err(Error::InvalidJson)?;
     let payload = Self::expand_payload(detached_payload, data.payload)?;
     let signature = data.signature;
-    self.decode_signature(payload, signature)
+    self.decode_signature(payload, signature, permitted_crits)
   }
 
   fn decode_signature<'a, 'b>(
     &self,
     payload: &'b [u8],
     jws_signature: JwsSignature<'a>,
+    permitted_crits: &[String],
   ) -> Result<JwsValidationItem<'b>> {
     let JwsSignature {
       header: unprotected_header,
@@ -277,7 +304,7 @@ impl Decoder {
     } = jws_signature;
 
     let protected_header: Option<JwsHeader> = protected.map(decode_b64_json).transpose()?;
-    validate_jws_headers(protected_header.as_ref(), unprotected_header.as_ref())?;
+    validate_jws_headers(protected_header.as_ref(), unprotected_header.as_ref(), permitted_crits)?;
 
     let protected_bytes: &[u8] = protected.map(str::as_bytes).unwrap_or_default();
     let signing_input: Box<[u8]> = create_message(protected_bytes, payload).into();
@@ -329,7 +356,7 @@ impl<'decoder, 'payload, 'signatures> Iterator for JwsValidationIter<'decoder, '
     self
       .signatures
       .next()
-      .map(|signature| self.decoder.decode_signature(self.payload, signature))
+      .map(|signature| self.decoder.decode_signature(self.payload, signature, &[]))
   }
 }
 
@@ -66,7 +66,20 @@ impl<'payload> CompactJwsEncoder<'payload> {
     protected_header: &JwsHeader,
     options: CompactJwsEncodingOptions,
   ) -> Result<Self> {
-    Self::validate_header(protected_header)?;
+    Self::new_with_options_and_crits(payload, protected_header, options, &[])
+  }
+
+  /// Similar to [`Self::new_with_options`](CompactJwsEncoder::new_with_options), but additionally accepts the header
+  /// parameters in `permitted_crits` in the "crit" header parameter. Only "b64" is accepted otherwise.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  pub fn new_with_options_and_crits(
+    payload: &'payload [u8],
+    protected_header: &JwsHeader,
+    options: CompactJwsEncodingOptions,
+    permitted_crits: &[String],
+  ) -> Result<Self> {
+    Self::validate_header(protected_header, permitted_crits)?;
     let encoded_protected_header: String = jwu::encode_b64_json(protected_header)?;
     let maybe_encoded: MaybeEncodedPayload<'_> = MaybeEncodedPayload::encode_if_b64(payload, Some(protected_header));
     let signing_input: Box<[u8]> =
@@ -94,8 +107,8 @@ impl<'payload> CompactJwsEncoder<'payload> {
     &self.signing_input
   }
 
-  fn validate_header(protected_header: &JwsHeader) -> Result<()> {
-    jwu::validate_jws_headers(Some(protected_header), None)
+  fn validate_header(protected_header: &JwsHeader, permitted_crits: &[String]) -> Result<()> {
+    jwu::validate_jws_headers(Some(protected_header), None, permitted_crits)
   }
 
   /// convert this into a JWS. The `signature` value is expected to be
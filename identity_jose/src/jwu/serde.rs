@@ -46,9 +46,14 @@ pub(crate) fn extract_b64(header: Option<&JwsHeader>) -> bool {
   header.and_then(JwsHeader::b64).unwrap_or(DEFAULT_B64)
 }
 
-pub(crate) fn validate_jws_headers(protected: Option<&JwsHeader>, unprotected: Option<&JwsHeader>) -> Result<()> {
+/// Validates the given JWS headers, accepting "b64" and the parameters in `permitted_crits` in the "crit" parameter.
+pub(crate) fn validate_jws_headers(
+  protected: Option<&JwsHeader>,
+  unprotected: Option<&JwsHeader>,
+  permitted_crits: &[String],
+) -> Result<()> {
   validate_disjoint(protected, unprotected)?;
-  validate_crit(protected, unprotected)?;
+  validate_crit(protected, unprotected, permitted_crits)?;
   validate_b64(protected, unprotected)?;
 
   Ok(())
@@ -59,11 +64,11 @@ pub(crate) fn validate_jws_headers(protected: Option<&JwsHeader>, unprotected: O
 /// 2. It is not encoded as an empty list.
 /// 3. It does not contain any header parameters defined by the
 ///  JOSE JWS/JWA specifications.
-/// 4. It's values are contained in the given `permitted` array.
+/// 4. It's values are "b64" or contained in the given `permitted` array.
 /// 5. All values in "crit" are present in at least one of the `protected` or `unprotected` headers.
 ///
 /// See (<https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11>)
-pub(crate) fn validate_crit<T>(protected: Option<&T>, unprotected: Option<&T>) -> Result<()>
+pub(crate) fn validate_crit<T>(protected: Option<&T>, unprotected: Option<&T>, permitted: &[String]) -> Result<()>
 where
   T: JoseHeader,
 {
@@ -89,7 +94,7 @@ where
     }
 
     // The "crit" parameter MUST be understood by the application.
-    if !PERMITTED_CRITS.contains(&AsRef::<str>::as_ref(value)) {
+    if !PERMITTED_CRITS.contains(&AsRef::<str>::as_ref(value)) && !permitted.contains(value) {
       return Err(Error::InvalidParam("unpermitted crit"));
     }
 
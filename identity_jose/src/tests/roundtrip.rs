@@ -8,7 +8,9 @@ use crypto::signatures::ed25519::SecretKey;
 use crate::jwk::Jwk;
 use crate::jwk::JwkParamsOkp;
 use crate::jwk::JwkType;
+use crate::jws::CharSet;
 use crate::jws::CompactJwsEncoder;
+use crate::jws::CompactJwsEncodingOptions;
 use crate::jws::Decoder;
 use crate::jws::JwsAlgorithm;
 use crate::jws::JwsHeader;
@@ -70,3 +72,35 @@ fn test_encoder_decoder_roundtrip() {
 
   assert_eq!(claims, recovered_claims);
 }
+
+#[test]
+fn test_custom_crit_roundtrip() {
+  let secret_key = SecretKey::generate().unwrap();
+
+  let mut header: JwsHeader = JwsHeader::new();
+  header.set_alg(JwsAlgorithm::EdDSA);
+  header.set_custom(
+    [("exp_policy".to_owned(), serde_json::json!("strict"))]
+      .into_iter()
+      .collect(),
+  );
+  header.set_crit(["exp_policy"]);
+  let permitted_crits: Vec<String> = vec!["exp_policy".to_owned()];
+  let options = CompactJwsEncodingOptions::NonDetached {
+    charset_requirements: CharSet::Default,
+  };
+
+  // Unknown critical parameters are rejected when encoding, unless explicitly permitted.
+  assert!(CompactJwsEncoder::new(b"payload", &header).is_err());
+  let encoder: CompactJwsEncoder<'_> =
+    CompactJwsEncoder::new_with_options_and_crits(b"payload", &header, options, &permitted_crits).unwrap();
+  let signature = secret_key.sign(encoder.signing_input()).to_bytes();
+  let jws = encoder.into_jws(&signature);
+
+  // The same holds when decoding.
+  let decoder = Decoder::new();
+  assert!(decoder.decode_compact_serialization(jws.as_bytes(), None).is_err());
+  assert!(decoder
+    .decode_compact_serialization_with_crits(jws.as_bytes(), None, &permitted_crits)
+    .is_ok());
+}
@@ -0,0 +1,119 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+
+use crate::Result;
+
+use super::commands::SendSyncCommand;
+use super::resolver::Resolver;
+
+/// A type resolving DIDs to their DID documents of type `DOC`.
+///
+/// This is the resolution interface a [`CachedResolver`] caches the results of. It is implemented for the
+/// [`Send`] + [`Sync`] [`Resolver`] and can be implemented for any other source of DID documents.
+#[async_trait::async_trait]
+pub trait DocumentResolver<DOC> {
+  /// Fetches the DID Document of the given DID.
+  async fn resolve<D: DID + Send + Sync>(&self, did: &D) -> Result<DOC>;
+}
+
+#[async_trait::async_trait]
+impl<DOC> DocumentResolver<DOC> for Resolver<DOC, SendSyncCommand<DOC>>
+where
+  DOC: Send + Sync + 'static,
+{
+  async fn resolve<D: DID + Send + Sync>(&self, did: &D) -> Result<DOC> {
+    Resolver::resolve(self, did).await
+  }
+}
+
+/// A [`DocumentResolver`] that caches resolved DID documents for a fixed time to live.
+///
+/// Documents are cached by DID, so that verifiers repeatedly validating credentials or presentations of the same
+/// issuers do not resolve their documents on every validation. A [`CachedResolver`] wrapping a [`Send`] + [`Sync`]
+/// resolver can itself be shared between threads.
+///
+/// # Concurrency
+///
+/// Lookups of the same DID are not coalesced: if several tasks resolve a DID that is not cached at the same time,
+/// each of them resolves it with the wrapped resolver and the document resolved last is the one that stays cached.
+pub struct CachedResolver<DOC = CoreDocument, R = Resolver<DOC>> {
+  resolver: R,
+  ttl: Duration,
+  clock: Box<dyn Fn() -> Instant + Send + Sync>,
+  cache: Mutex<HashMap<String, (Instant, DOC)>>,
+}
+
+impl<DOC, R> CachedResolver<DOC, R>
+where
+  R: DocumentResolver<DOC>,
+  DOC: Clone,
+{
+  /// Constructs a new [`CachedResolver`] resolving documents with `resolver` and caching them for `ttl`.
+  pub fn new(resolver: R, ttl: Duration) -> Self {
+    Self::with_clock(resolver, ttl, Instant::now)
+  }
+
+  /// Constructs a new [`CachedResolver`] measuring the age of cached documents with `clock`.
+  pub(crate) fn with_clock(resolver: R, ttl: Duration, clock: impl Fn() -> Instant + Send + Sync + 'static) -> Self {
+    Self {
+      resolver,
+      ttl,
+      clock: Box::new(clock),
+      cache: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Fetches the DID Document of the given DID, returning the cached document if it was resolved less than the time
+  /// to live ago.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the document is not cached and its resolution by the wrapped resolver fails.
+  pub async fn resolve<D: DID + Send + Sync>(&self, did: &D) -> Result<DOC> {
+    if let Some(document) = self.cached(did.as_str()) {
+      return Ok(document);
+    }
+
+    let document: DOC = self.resolver.resolve(did).await?;
+    let resolved_at: Instant = (self.clock)();
+    self
+      .lock()
+      .insert(did.as_str().to_owned(), (resolved_at, document.clone()));
+
+    Ok(document)
+  }
+
+  /// Removes all cached documents.
+  pub fn clear(&self) {
+    self.lock().clear();
+  }
+
+  /// Returns the cached document of `did` if it has not expired yet, evicting it otherwise.
+  fn cached(&self, did: &str) -> Option<DOC> {
+    let now: Instant = (self.clock)();
+    let mut cache = self.lock();
+    match cache.get(did) {
+      Some((resolved_at, document)) if now.saturating_duration_since(*resolved_at) < self.ttl => Some(document.clone()),
+      Some(_) => {
+        cache.remove(did);
+        None
+      }
+      None => None,
+    }
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HashMap<String, (Instant, DOC)>> {
+    // The cache is never left in an inconsistent state, so a poisoned lock can safely be recovered.
+    self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
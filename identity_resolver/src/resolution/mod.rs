@@ -1,6 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod cached_resolver;
 mod commands;
 mod resolver;
 #[cfg(test)]
@@ -9,6 +10,8 @@ mod tests;
 use self::commands::SingleThreadedCommand;
 use identity_document::document::CoreDocument;
 
+pub use cached_resolver::CachedResolver;
+pub use cached_resolver::DocumentResolver;
 pub use resolver::Resolver;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
 pub type SingleThreadedResolver<DOC = CoreDocument> = Resolver<DOC, SingleThreadedCommand<DOC>>;
@@ -0,0 +1,82 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+
+use crate::CachedResolver;
+use crate::Resolver;
+
+/// A resolver for the "foo" method counting the number of resolutions.
+fn counting_resolver() -> (Resolver<CoreDocument>, Arc<AtomicUsize>) {
+  let resolutions = Arc::new(AtomicUsize::new(0));
+  let handler_resolutions = resolutions.clone();
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+    let resolutions = handler_resolutions.clone();
+    async move {
+      resolutions.fetch_add(1, Ordering::SeqCst);
+      DocumentBuilder::default().id(did).build()
+    }
+  });
+  (resolver, resolutions)
+}
+
+#[tokio::test]
+async fn cached_document_is_reused_within_ttl() {
+  let (resolver, resolutions) = counting_resolver();
+  let resolver: CachedResolver = CachedResolver::new(resolver, Duration::from_secs(3600));
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  let other_did: CoreDID = CoreDID::parse("did:foo:5678").unwrap();
+
+  for _ in 0..3 {
+    assert_eq!(resolver.resolve(&did).await.unwrap().id(), &did);
+  }
+  assert_eq!(resolutions.load(Ordering::SeqCst), 1);
+
+  // Documents are cached per DID.
+  assert_eq!(resolver.resolve(&other_did).await.unwrap().id(), &other_did);
+  assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+
+  resolver.clear();
+  resolver.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn expired_document_is_refetched() {
+  let (resolver, resolutions) = counting_resolver();
+  let now = Arc::new(Mutex::new(Instant::now()));
+  let clock = now.clone();
+  let resolver: CachedResolver =
+    CachedResolver::with_clock(resolver, Duration::from_secs(60), move || *clock.lock().unwrap());
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+
+  resolver.resolve(&did).await.unwrap();
+  *now.lock().unwrap() += Duration::from_secs(59);
+  resolver.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 1);
+
+  *now.lock().unwrap() += Duration::from_secs(1);
+  resolver.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn resolution_errors_are_not_cached() {
+  let (resolver, _) = counting_resolver();
+  let resolver: CachedResolver = CachedResolver::new(resolver, Duration::from_secs(3600));
+
+  // No handler is attached for the "bar" method.
+  let did: CoreDID = CoreDID::parse("did:bar:1234").unwrap();
+  assert!(resolver.resolve(&did).await.is_err());
+  assert!(resolver.resolve(&did).await.is_err());
+}
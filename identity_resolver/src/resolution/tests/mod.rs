@@ -2,5 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::resolver::*;
+mod cached_resolver;
 mod resolution;
 mod send_sync;
@@ -24,3 +24,13 @@ where
   let resolver = Resolver::<DOC>::new();
   is_send(resolver.resolve(&did));
 }
+
+#[allow(dead_code)]
+fn cached_resolver_methods_give_send_futures<DOC, D>(did: D)
+where
+  DOC: AsRef<CoreDocument> + Clone + Send + Sync + 'static,
+  D: DID + Send + Sync + 'static,
+{
+  let resolver = crate::CachedResolver::<DOC>::new(Resolver::<DOC>::new(), std::time::Duration::from_secs(60));
+  is_send(resolver.resolve(&did));
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_did::DIDUrl;
 use identity_verification::MethodData;
 use identity_verification::VerificationMethod;
 use seahash::SeaHasher;
@@ -46,11 +47,18 @@ pub struct MethodDigest {
 impl MethodDigest {
   /// Creates a new [`MethodDigest`].
   pub fn new(verification_method: &VerificationMethod) -> Result<Self, MethodDigestConstructionError> {
+    Self::from_parts(verification_method.id(), verification_method.data())
+  }
+
+  /// Creates a new [`MethodDigest`] from the `id` and `method_data` of a [`VerificationMethod`], without
+  /// constructing the method.
+  ///
+  /// The digest is identical to the one [`MethodDigest::new`] creates for a method with the same `id` and data.
+  pub fn from_parts(id: &DIDUrl, method_data: &MethodData) -> Result<Self, MethodDigestConstructionError> {
     // Method digest version 0 formula: SeaHash(<fragment><JWK thumbprint if JWK else decoded public key>)
     use MethodDigestConstructionErrorKind::*;
     let mut hasher: SeaHasher = SeaHasher::new();
-    let fragment: &str = verification_method.id().fragment().ok_or(MissingIdFragment)?;
-    let method_data: &MethodData = verification_method.data();
+    let fragment: &str = id.fragment().ok_or(MissingIdFragment)?;
 
     hasher.write(fragment.as_bytes());
 
@@ -139,6 +147,15 @@ mod test {
     assert_eq!(method_digest, method_digest_unpacked);
   }
 
+  #[test]
+  fn from_parts() {
+    let verification_method: VerificationMethod = crate::storage::tests::test_utils::create_verification_method();
+    assert_eq!(
+      MethodDigest::from_parts(verification_method.id(), verification_method.data()).unwrap(),
+      MethodDigest::new(&verification_method).unwrap()
+    );
+  }
+
   #[test]
   fn unpack() {
     let packed: Vec<u8> = vec![0, 255, 212, 82, 63, 57, 19, 134, 193];
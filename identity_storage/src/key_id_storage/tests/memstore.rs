@@ -3,6 +3,7 @@
 
 use crate::key_id_storage::memstore::KeyIdMemstore;
 
+use crate::key_id_storage::tests::utils::test_concurrent_insertion;
 use crate::key_id_storage::tests::utils::test_storage_operations;
 
 #[tokio::test]
@@ -10,3 +11,9 @@ async fn test_memstore() {
   let memstore: KeyIdMemstore = KeyIdMemstore::new();
   test_storage_operations(memstore).await;
 }
+
+#[tokio::test]
+async fn test_memstore_concurrent_insertion() {
+  let memstore: KeyIdMemstore = KeyIdMemstore::new();
+  test_concurrent_insertion(memstore).await;
+}
@@ -43,3 +43,24 @@ pub(crate) async fn test_storage_operations(storage: impl KeyIdStorage) {
   let _expected_error: KeyIdStorageError = KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound);
   assert!(matches!(repeat_deletion_result.unwrap_err(), _expected_error));
 }
+
+pub(crate) async fn test_concurrent_insertion(storage: impl KeyIdStorage) {
+  let verification_method = create_verification_method();
+  let method_digest: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+
+  // Race many insertions under the same method digest.
+  let insertions = (0..32).map(|idx| storage.insert_key_id(method_digest.clone(), KeyId::new(format!("keyid-{idx}"))));
+  let results: Vec<KeyIdStorageResult<()>> = futures::future::join_all(insertions).await;
+
+  // Exactly one insertion wins, all others are rejected.
+  assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+  assert!(results
+    .iter()
+    .filter_map(|result| result.as_ref().err())
+    .all(|err| matches!(err.kind(), KeyIdStorageErrorKind::KeyIdAlreadyExists)));
+
+  // The stored key id is the one of the winning insertion.
+  let winner: usize = results.iter().position(Result::is_ok).unwrap();
+  let key_id: KeyId = storage.get_key_id(&method_digest).await.unwrap();
+  assert_eq!(key_id, KeyId::new(format!("keyid-{winner}")));
+}
@@ -11,22 +11,33 @@ use crate::key_storage::KeyStorageError;
 pub enum JwkStorageDocumentError {
   /// Caused by a failure in the key storage.
   #[error("storage operation failed: key storage error")]
-  KeyStorageError(KeyStorageError),
+  KeyStorageError(#[source] KeyStorageError),
   /// Caused by a failure in the key id storage.
   #[error("storage operation failed: key id storage error")]
-  KeyIdStorageError(KeyIdStorageError),
+  KeyIdStorageError(#[source] KeyIdStorageError),
+  /// Caused by an attempt to store a key id under a method digest for which a key id is already stored.
+  ///
+  /// This happens when two methods with the same fragment and key material are generated with the same storage.
+  #[error("could not store key id: a key id already exists under the same method digest")]
+  MethodDigestCollision(#[source] KeyIdStorageError),
   /// Caused by an attempt to add a method with a fragment that already exists.
   #[error("could not add method: the fragment already exists")]
   FragmentAlreadyExists,
   /// Caused by a missing verification method.
   #[error("method not found")]
   MethodNotFound,
+  /// Caused by the usage of a method that is not part of the expected verification relationship.
+  #[error("invalid method scope: the method is not part of the expected verification relationship")]
+  InvalidMethodScope,
   /// Caused by the usage of a non-JWK method where a JWK method is expected.
   #[error("invalid method data format: expected publicKeyJwk")]
   NotPublicKeyJwk,
   /// Caused by an invalid JWS algorithm.
   #[error("invalid JWS algorithm")]
   InvalidJwsAlgorithm,
+  /// Caused by a `kid` that does not identify the signing method in the signing document.
+  #[error("invalid kid: expected a DID URL identifying the signing method")]
+  InvalidKid,
   /// Caused by an invalid JWP algorithm.
   #[error("invalid JWP algorithm")]
   InvalidJwpAlgorithm,
@@ -43,9 +54,20 @@ pub enum JwkStorageDocumentError {
   /// Caused by an encoding error.
   #[error("could not produce jwt: encoding error")]
   EncodingError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by a payload that cannot be embedded into a compact JWS, e.g. an unencoded payload containing
+  /// characters outside of the permitted character set.
+  #[error("could not produce jws: invalid payload")]
+  InvalidJwsPayload(#[source] identity_verification::jose::error::Error),
+  /// Caused by a JWS header that cannot be encoded, e.g. one whose `crit` parameter lists a parameter that is not
+  /// set or not permitted.
+  #[error("could not produce jws: invalid header")]
+  InvalidJwsHeader(#[source] identity_verification::jose::error::Error),
   /// Caused by a failure to construct a method digest.
   #[error("unable to produce method digest")]
   MethodDigestConstructionError(#[source] MethodDigestConstructionError),
+  /// Caused by a credential status referencing a missing or invalid `RevocationBitmap2022` service.
+  #[error("could not assign credential status: invalid revocation bitmap service")]
+  InvalidRevocationBitmapService(#[source] identity_credential::revocation::RevocationError),
   /// Caused by a failure during (de)serialization of JWS claims.
   #[error("could not produce JWS payload from the given claims: serialization failed")]
   ClaimsSerializationError(#[source] identity_credential::Error),
@@ -63,11 +85,34 @@ pub enum JwkStorageDocumentError {
 
 #[cfg(test)]
 mod tests {
+  use std::error::Error;
+
   use super::JwkStorageDocumentError;
+  use crate::key_id_storage::KeyIdStorageError;
+  use crate::key_id_storage::KeyIdStorageErrorKind;
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
   fn is_send_sync<T: Send + Sync + 'static>(_input: T) {}
 
   #[test]
   fn error_is_send_sync() {
     is_send_sync(JwkStorageDocumentError::FragmentAlreadyExists);
   }
+
+  #[test]
+  fn source_is_wrapped_error() {
+    let error = JwkStorageDocumentError::KeyStorageError(KeyStorageError::new(KeyStorageErrorKind::KeyNotFound));
+    let source: &KeyStorageError = error.source().unwrap().downcast_ref().unwrap();
+    assert!(matches!(source.kind(), KeyStorageErrorKind::KeyNotFound));
+
+    let error =
+      JwkStorageDocumentError::KeyIdStorageError(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound));
+    let source: &KeyIdStorageError = error.source().unwrap().downcast_ref().unwrap();
+    assert!(matches!(source.kind(), KeyIdStorageErrorKind::KeyIdNotFound));
+
+    let error =
+      JwkStorageDocumentError::EncodingError(Box::new(KeyStorageError::new(KeyStorageErrorKind::Unspecified)));
+    let source: &KeyStorageError = error.source().unwrap().downcast_ref().unwrap();
+    assert!(matches!(source.kind(), KeyStorageErrorKind::Unspecified));
+  }
 }
@@ -23,6 +23,7 @@ use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
+use identity_verification::jose::error::Error as JoseError;
 use identity_verification::jose::jws::CompactJwsEncoder;
 use identity_verification::jose::jws::CompactJwsEncodingOptions;
 use identity_verification::jose::jws::JwsAlgorithm;
@@ -203,10 +204,14 @@ macro_rules! generate_method_for_document_type {
       };
 
       // Insert the generated `KeyId` into storage under the computed method digest and handle the error if the
-      // operation fails.
+      // operation fails. An existing entry under the same digest is never overwritten, as that would silently break
+      // signing with the method it belongs to.
       if let Err(error) = <I as KeyIdStorage>::insert_key_id(&storage.key_id_storage(), method_digest, key_id.clone())
         .await
-        .map_err(Error::KeyIdStorageError)
+        .map_err(|err| match err.kind() {
+          crate::key_id_storage::KeyIdStorageErrorKind::KeyIdAlreadyExists => Error::MethodDigestCollision(err),
+          _ => Error::KeyIdStorageError(err),
+        })
       {
         // Remove the method from the document as it can no longer be used.
         let _ = document.remove_method(&method_id);
@@ -352,6 +357,22 @@ impl JwkDocumentExt for CoreDocument {
   {
     // Obtain the method corresponding to the given fragment.
     let method: &VerificationMethod = self.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+
+    // Make sure the method is part of the expected verification relationship, if any.
+    if let Some(scope) = options.method_scope {
+      if self.resolve_method(fragment, Some(scope)).is_none() {
+        return Err(Error::InvalidMethodScope);
+      }
+    }
+
+    // Make sure an explicitly set kid identifies the signing method, if required.
+    if let (Some(kid), true) = (&options.kid, options.require_resolvable_kid) {
+      let kid_method_id: DIDUrl = DIDUrl::parse(kid).map_err(|_| Error::InvalidKid)?;
+      if self.resolve_method(&kid_method_id, None).map(VerificationMethod::id) != Some(method.id()) {
+        return Err(Error::InvalidKid);
+      }
+    }
+
     let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
       return Err(Error::NotPublicKeyJwk);
     };
@@ -382,13 +403,18 @@ impl JwkDocumentExt for CoreDocument {
         header.set_jwk(jwk.clone())
       };
 
+      let mut crit: Vec<String> = Vec::new();
       if let Some(b64) = options.b64 {
         // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
         if !b64 {
           header.set_b64(b64);
-          header.set_crit(["b64"]);
+          crit.push("b64".to_owned());
         }
       };
+      crit.extend(options.crit.iter().flatten().cloned());
+      if !crit.is_empty() {
+        header.set_crit(crit);
+      }
 
       if let Some(typ) = &options.typ {
         header.set_typ(typ.clone())
@@ -418,6 +444,10 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map_err(Error::KeyIdStorageError)?;
 
+    if options.require_utf8_payload && !options.detached_payload {
+      std::str::from_utf8(payload).map_err(|err| Error::InvalidJwsPayload(JoseError::InvalidUtf8(err)))?;
+    }
+
     // Extract Compact JWS encoding options.
     let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
       // We use this as a default and don't provide the extra UrlSafe check for now.
@@ -429,8 +459,19 @@ impl JwkDocumentExt for CoreDocument {
       CompactJwsEncodingOptions::Detached
     };
 
-    let jws_encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new_with_options(payload, &header, encoding_options)
-      .map_err(|err| Error::EncodingError(err.into()))?;
+    let jws_encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new_with_options_and_crits(
+      payload,
+      &header,
+      encoding_options,
+      options.crit.as_deref().unwrap_or_default(),
+    )
+    .map_err(|err| match err {
+      // Raised by the charset validation of unencoded payloads.
+      JoseError::InvalidContent(_) => Error::InvalidJwsPayload(err),
+      // Raised by the validation and the serialization of the protected header.
+      JoseError::InvalidParam(_) | JoseError::InvalidJson(_) => Error::InvalidJwsHeader(err),
+      err => Error::EncodingError(err.into()),
+    })?;
     let signature = <K as JwkStorage>::sign(storage.key_storage(), &key_id, jws_encoder.signing_input(), jwk)
       .await
       .map_err(Error::KeyStorageError)?;
@@ -12,14 +12,19 @@ use crate::Storage;
 use crate::StorageResult;
 use async_trait::async_trait;
 use identity_core::common::Object;
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_credential::credential::Credential;
 use identity_credential::credential::Jpt;
 use identity_credential::credential::JwpCredentialOptions;
+use identity_credential::credential::RevocationBitmapStatus;
 use identity_credential::presentation::JwpPresentationOptions;
 use identity_credential::presentation::SelectiveDisclosurePresentation;
+use identity_credential::revocation::RevocationBitmap;
+use identity_credential::revocation::RevocationError;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
+use identity_document::service::Service;
 use identity_verification::MethodData;
 use identity_verification::MethodScope;
 use identity_verification::VerificationMethod;
@@ -150,17 +155,13 @@ impl JwpDocumentExt for CoreDocument {
       .parse()
       .map_err(|_| Error::InvalidJwpAlgorithm)?;
 
-    let typ = "JPT".to_string();
-
     let kid = if let Some(ref kid) = options.kid {
       kid.clone()
     } else {
       method.id().to_string()
     };
 
-    let mut issuer_header = IssuerProtectedHeader::new(alg);
-    issuer_header.set_typ(Some(typ));
-    issuer_header.set_kid(Some(kid));
+    let issuer_header = issuer_protected_header(alg, kid);
 
     // Get the key identifier corresponding to the given method from the KeyId storage.
     let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
@@ -242,9 +243,21 @@ impl JwpDocumentExt for CoreDocument {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
   {
-    let jpt_claims = credential
-      .serialize_jpt(custom_claims)
-      .map_err(Error::ClaimsSerializationError)?;
+    let jpt_claims = match &options.revocation_bitmap_status {
+      Some(entry) => {
+        // `T` is not required to be `Clone`, so the copy carrying the assigned status is made through JSON.
+        let mut credential: Credential<T> = credential
+          .to_json_value()
+          .and_then(Credential::from_json_value)
+          .map_err(|err| identity_credential::Error::JwtClaimsSetSerializationError(err.into()))
+          .map_err(Error::ClaimsSerializationError)?;
+        credential.credential_status =
+          Some(revocation_bitmap_status(self, &entry.service_fragment, entry.index)?.into());
+        credential.serialize_jpt(custom_claims)
+      }
+      None => credential.serialize_jpt(custom_claims),
+    }
+    .map_err(Error::ClaimsSerializationError)?;
 
     self
       .create_issued_jwp(storage, fragment, &jpt_claims, options)
@@ -265,6 +278,68 @@ impl JwpDocumentExt for CoreDocument {
   }
 }
 
+/// Estimates the length of the JPT in compact serialization that issuing `credential` with `alg` and `kid` through
+/// [`JwpDocumentExt::create_credential_jpt`] produces, e.g. to decide whether it fits into a QR code.
+///
+/// The protected header and payloads are encoded exactly as when issuing; only the proof is assumed to have the
+/// signature length of `alg`. A status assigned through
+/// [`JwpCredentialOptions::revocation_bitmap_status`] is not accounted for.
+///
+/// # Errors
+/// Fails with [`JwkStorageDocumentError::InvalidJwpAlgorithm`](Error::InvalidJwpAlgorithm) if `alg` cannot be used to
+/// issue a JPT.
+pub fn estimate_jpt_size<T>(
+  credential: &Credential<T>,
+  alg: ProofAlgorithm,
+  kid: &str,
+  custom_claims: Option<Object>,
+) -> StorageResult<usize>
+where
+  T: ToOwned<Owned = T> + Serialize + DeserializeOwned,
+{
+  let proof_length: usize = match alg {
+    // A BBS signature consists of a G1 point and a scalar.
+    ProofAlgorithm::BLS12381_SHA256 | ProofAlgorithm::BLS12381_SHAKE256 => 80,
+    _ => return Err(Error::InvalidJwpAlgorithm),
+  };
+
+  let jpt_claims = credential
+    .serialize_jpt(custom_claims)
+    .map_err(Error::ClaimsSerializationError)?;
+
+  JwpIssuedBuilder::new(issuer_protected_header(alg, kid.to_owned()), jpt_claims)
+    .build_with_proof(vec![0; proof_length])
+    .map_err(|_| Error::JwpBuildingError)?
+    .encode(SerializationType::COMPACT)
+    .map(|jpt| jpt.len())
+    .map_err(|err| Error::EncodingError(Box::new(err)))
+}
+
+/// Creates the protected header of a JPT issued with `alg` by the method identified by `kid`.
+fn issuer_protected_header(alg: ProofAlgorithm, kid: String) -> IssuerProtectedHeader {
+  let mut issuer_header = IssuerProtectedHeader::new(alg);
+  issuer_header.set_typ(Some("JPT".to_owned()));
+  issuer_header.set_kid(Some(kid));
+  issuer_header
+}
+
+/// Creates a [`RevocationBitmapStatus`] for `index` in the `RevocationBitmap2022` service of `document` identified by
+/// `service_fragment`.
+fn revocation_bitmap_status(
+  document: &CoreDocument,
+  service_fragment: &str,
+  index: u32,
+) -> StorageResult<RevocationBitmapStatus> {
+  let service: &Service = document
+    .resolve_service(service_fragment)
+    .ok_or(Error::InvalidRevocationBitmapService(RevocationError::InvalidService(
+      "revocation bitmap service not found",
+    )))?;
+  RevocationBitmap::try_from(service).map_err(Error::InvalidRevocationBitmapService)?;
+
+  Ok(RevocationBitmapStatus::new(service.id().clone(), index))
+}
+
 // ====================================================================================================================
 // IotaDocument
 // ====================================================================================================================
@@ -3,6 +3,7 @@
 
 use identity_core::common::Object;
 use identity_core::common::Url;
+use identity_verification::MethodScope;
 
 /// Options for creating a JSON Web Signature.
 #[non_exhaustive]
@@ -52,14 +53,41 @@ pub struct JwsSignatureOptions {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub kid: Option<String>,
 
+  /// Whether an explicitly set `kid` must be a DID URL identifying the signing method in the signing document.
+  ///
+  /// Verifiers locate the verification method through the `kid`, so a `kid` that does not resolve to the signing
+  /// method produces a JWS that cannot be verified against the document. If set, signing fails for such a `kid`.
+  pub require_resolvable_kid: bool,
+
   /// Whether the payload should be detached from the JWS.
   ///
   /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#appendix-F).
   pub detached_payload: bool,
 
+  /// Whether a non-detached payload must be valid UTF-8, as expected by consumers of textual payloads such as JWTs.
+  ///
+  /// If set, signing fails for payloads containing invalid UTF-8 instead of producing a JWS that such consumers
+  /// cannot parse.
+  pub require_utf8_payload: bool,
+
   /// Additional header parameters.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub custom_header_parameters: Option<Object>,
+
+  /// Custom header parameters to list in the `crit` header parameter, signalling that verifiers must understand them.
+  ///
+  /// The parameters must be set in `custom_header_parameters`. `b64` is added automatically when `b64` is `false`.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.11)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub crit: Option<Vec<String>>,
+
+  /// The verification relationship the signing method is expected to be part of.
+  ///
+  /// If set, signing fails unless the method identified by the given fragment is in this scope, e.g. to prevent
+  /// issuing credentials with a method that is only registered for authentication.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub method_scope: Option<MethodScope>,
 }
 
 impl JwsSignatureOptions {
@@ -110,15 +138,39 @@ impl JwsSignatureOptions {
     self
   }
 
+  /// Replace the value of the `require_resolvable_kid` field.
+  pub fn require_resolvable_kid(mut self, value: bool) -> Self {
+    self.require_resolvable_kid = value;
+    self
+  }
+
   /// Replace the value of the `detached_payload` field.
   pub fn detached_payload(mut self, value: bool) -> Self {
     self.detached_payload = value;
     self
   }
 
+  /// Replace the value of the `require_utf8_payload` field.
+  pub fn require_utf8_payload(mut self, value: bool) -> Self {
+    self.require_utf8_payload = value;
+    self
+  }
+
   /// Adds additional header parameters.
   pub fn custom_header_parameters(mut self, value: Object) -> Self {
     self.custom_header_parameters = Some(value);
     self
   }
+
+  /// Replace the value of the `crit` field.
+  pub fn crit(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.crit = Some(value.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// Replace the value of the `method_scope` field.
+  pub fn method_scope(mut self, value: MethodScope) -> Self {
+    self.method_scope = Some(value);
+    self
+  }
 }
@@ -1,6 +1,8 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use async_trait::async_trait;
+use crypto::signatures::ed25519::SecretKey;
 use identity_core::common::Object;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
@@ -12,6 +14,7 @@ use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwsVerificationOptions;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::error::Error as JoseError;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jwk::Jwk;
 use identity_verification::jws::DecodedJws;
@@ -20,10 +23,17 @@ use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 
 use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkGenOutput;
 use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageResult;
+use crate::key_storage::KeyType;
 use crate::storage::JwsSignatureOptions;
 
+use crate::storage::tests::test_utils::encode_public_ed25519_jwk;
 use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
 use crate::Storage;
 
 type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
@@ -275,6 +285,138 @@ async fn create_jws_detached() {
     .is_ok());
 }
 
+#[tokio::test]
+async fn verify_jws_allow_unencoded_payload() {
+  let (document, storage, fragment) = setup_with_method().await;
+
+  let payload: &[u8] = b"test";
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().b64(false).detached_payload(true);
+  let jws: Jws = document
+    .create_jws(&storage, fragment.as_ref(), payload, &signature_options)
+    .await
+    .unwrap();
+
+  // Unencoded payloads are accepted by default.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      Some(payload),
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new(),
+    )
+    .is_ok());
+
+  // Strict verifiers reject them even though the signature is valid.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      Some(payload),
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new().allow_unencoded_payload(false),
+    )
+    .is_err());
+
+  // Encoded payloads are unaffected.
+  let jws: Jws = document
+    .create_jws(&storage, fragment.as_ref(), payload, &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new().allow_unencoded_payload(false),
+    )
+    .is_ok());
+}
+
+#[tokio::test]
+async fn verify_jws_permitted_crits() {
+  let (document, storage, fragment) = setup_with_method().await;
+
+  let mut custom_header_parameters = Object::new();
+  custom_header_parameters.insert("exp_policy".to_owned(), "strict".into());
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new()
+    .custom_header_parameters(custom_header_parameters)
+    .crit(["exp_policy"]);
+  let jws: Jws = document
+    .create_jws(&storage, fragment.as_ref(), b"test", &signature_options)
+    .await
+    .unwrap();
+
+  // Verifiers that understand the critical parameter accept the JWS.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new().permit_crit("exp_policy"),
+    )
+    .is_ok());
+
+  // Verifiers that do not understand it must reject the JWS.
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new(),
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn create_jws_invalid_unencoded_payload() {
+  let (document, storage, fragment) = setup_with_method().await;
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().b64(false);
+
+  // Periods are never allowed in unencoded compact payloads.
+  // Control characters are outside of the default character set.
+  for payload in [b"te.st".as_slice(), b"te\nst".as_slice()] {
+    let error = document
+      .create_jws(&storage, &fragment, payload, &signature_options)
+      .await
+      .unwrap_err();
+    assert!(matches!(error, JwkStorageDocumentError::InvalidJwsPayload(_)));
+  }
+
+  // Both are fine when the payload is detached.
+  assert!(document
+    .create_jws(
+      &storage,
+      &fragment,
+      b"te.st",
+      &JwsSignatureOptions::new().b64(false).detached_payload(true),
+    )
+    .await
+    .is_ok());
+}
+
+#[tokio::test]
+async fn create_jws_invalid_header() {
+  let (document, storage, fragment) = setup_with_method().await;
+
+  // A critical parameter must be set in the header.
+  let error = document
+    .create_jws(
+      &storage,
+      &fragment,
+      b"test",
+      &JwsSignatureOptions::new().crit(["exp_policy"]),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidJwsHeader(_)));
+
+  // Parameters defined by the JWS specification must not be critical.
+  let error = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new().crit(["kid"]))
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidJwsHeader(_)));
+}
+
 #[tokio::test]
 async fn create_jws_with_custom_kid() {
   let (document, storage, fragment) = setup_with_method().await;
@@ -297,6 +439,148 @@ async fn create_jws_with_custom_kid() {
   assert_eq!(decoded.protected.kid().unwrap(), key_id);
 }
 
+#[tokio::test]
+async fn create_jws_require_resolvable_kid() {
+  let (document, storage, fragment) = setup_with_method().await;
+  let payload: &[u8] = b"test";
+
+  // Without the option, any kid is accepted.
+  assert!(document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::new().kid("my-key-id")
+    )
+    .await
+    .is_ok());
+
+  let error = document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::new().kid("my-key-id").require_resolvable_kid(true),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidKid));
+
+  // A DID URL that does not identify the signing method.
+  let other_method_id: String = document.id().clone().join("#other-key").unwrap().to_string();
+  let error = document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::new()
+        .kid(other_method_id)
+        .require_resolvable_kid(true),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidKid));
+
+  let method_id: String = document.id().clone().join(format!("#{fragment}")).unwrap().to_string();
+  let jws: Jws = document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::new().kid(method_id).require_resolvable_kid(true),
+    )
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+}
+
+#[tokio::test]
+async fn create_jws_with_method_scope() {
+  let (mut document, storage, fragment) = setup_with_method().await;
+  let payload: &[u8] = b"test";
+
+  // The method is only an embedded verification method, so signing as an assertion method must fail.
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().method_scope(
+    MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
+  );
+  let error = document
+    .create_jws(&storage, &fragment, payload, &signature_options)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidMethodScope));
+
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().method_scope(MethodScope::VerificationMethod);
+  assert!(document
+    .create_jws(&storage, &fragment, payload, &signature_options)
+    .await
+    .is_ok());
+
+  // An authentication-only method cannot be used to sign as an assertion method.
+  let authentication_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationRelationship(MethodRelationship::Authentication),
+    )
+    .await
+    .unwrap();
+  let signature_options: JwsSignatureOptions = JwsSignatureOptions::new().method_scope(
+    MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
+  );
+  let error = document
+    .create_jws(&storage, &authentication_fragment, payload, &signature_options)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::InvalidMethodScope));
+}
+
+#[tokio::test]
+async fn create_jws_require_utf8_payload() {
+  let (document, storage, fragment) = setup_with_method().await;
+  let payload: &[u8] = &[0xff, 0xfe, 0xfd];
+
+  assert!(document
+    .create_jws(&storage, &fragment, payload, &JwsSignatureOptions::default())
+    .await
+    .is_ok());
+
+  let error = document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::default().require_utf8_payload(true),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    error,
+    JwkStorageDocumentError::InvalidJwsPayload(JoseError::InvalidUtf8(_))
+  ));
+
+  // Detached payloads are not encoded into the JWS.
+  assert!(document
+    .create_jws(
+      &storage,
+      &fragment,
+      payload,
+      &JwsSignatureOptions::default()
+        .require_utf8_payload(true)
+        .detached_payload(true),
+    )
+    .await
+    .is_ok());
+}
+
 #[tokio::test]
 async fn signing_credential() {
   let (mut document, storage) = setup();
@@ -380,6 +664,91 @@ async fn purging() {
   assert_eq!(storage.key_storage().count().await, 0);
 }
 
+/// A [`JwkStorage`] that stores the same key material on every call to `generate`.
+struct FixedKeyStore {
+  inner: JwkMemStore,
+  private_jwk: Jwk,
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorage for FixedKeyStore {
+  async fn generate(&self, _key_type: KeyType, _alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    let key_id: KeyId = self.inner.insert(self.private_jwk.clone()).await?;
+    let public_jwk: Jwk = self.private_jwk.to_public().expect("should only panic if kty == oct");
+    Ok(JwkGenOutput::new(key_id, public_jwk))
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    self.inner.insert(jwk).await
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    self.inner.sign(key_id, data, public_key).await
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    self.inner.delete(key_id).await
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    self.inner.exists(key_id).await
+  }
+}
+
+#[tokio::test]
+async fn generation_with_colliding_method_digest() {
+  let secret_key: SecretKey = SecretKey::generate().unwrap();
+  let mut private_jwk: Jwk = encode_public_ed25519_jwk(&secret_key.public_key());
+  private_jwk.try_okp_params_mut().unwrap().d = Some(encode_b64(secret_key.to_bytes().as_ref()));
+  let storage = Storage::new(
+    FixedKeyStore {
+      inner: JwkMemStore::new(),
+      private_jwk,
+    },
+    KeyIdMemstore::new(),
+  );
+
+  let fragment: &str = "#key-1";
+  let mut document_a: CoreDocument = CoreDocument::from_json(MOCK_DOCUMENT_JSON).unwrap();
+  let mut document_b: CoreDocument = CoreDocument::from_json(r#"{"id": "did:foo:0xabcdef"}"#).unwrap();
+
+  document_a
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some(fragment),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+
+  // Same fragment and same key material, hence the same method digest.
+  let error = document_b
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some(fragment),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::MethodDigestCollision(_)));
+
+  // The failed generation is rolled back.
+  assert!(document_b.resolve_method(fragment, None).is_none());
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert_eq!(storage.key_storage().inner.count().await, 1);
+
+  // The first method is still usable.
+  assert!(document_a
+    .create_jws(&storage, fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+}
+
 #[cfg(feature = "iota-document")]
 mod iota_document_tests {
   // Write a single test for the IotaDocument case just to check that it works
@@ -16,6 +16,7 @@ use identity_credential::validator::JwtCredentialValidator;
 use identity_credential::validator::JwtCredentialValidatorUtils;
 use identity_credential::validator::JwtValidationError;
 use identity_credential::validator::StatusCheck;
+use identity_credential::validator::ValidationUnit;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::service::Service;
@@ -115,6 +116,49 @@ async fn invalid_expiration_or_issuance_date() {
   invalid_expiration_or_issuance_date_impl(test_utils::setup_iotadocument(None, None).await).await;
 }
 
+#[tokio::test]
+async fn missing_expiration_date() {
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    subject_storage: _,
+    subject_method_fragment: _,
+  } = test_utils::setup_coredocument(None, None).await;
+
+  let CredentialSetup { mut credential, .. } =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  credential.expiration_date = None;
+
+  let jws = issuer_doc
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      method_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  // A credential without an expiration date never expires.
+  let options = JwtCredentialValidationOptions::default();
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate::<_, Object>(&jws, &issuer_doc, &options, FailFast::FirstError)
+    .is_ok());
+
+  // Unless an expiration date is required.
+  let validation_errors = JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate::<_, Object>(&jws, &issuer_doc, &options.require_expiry(true), FailFast::AllErrors)
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [JwtValidationError::MissingExpirationDate]
+  ));
+}
+
 async fn full_validation_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -161,6 +205,97 @@ async fn full_validation() {
   full_validation_impl(test_utils::setup_iotadocument(None, None).await).await;
 }
 
+async fn validation_progress_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    subject_storage: _,
+    subject_method_fragment: _,
+  } = setup;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+
+  let jwt: Jwt = issuer_doc
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      method_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  let validate = |options: &JwtCredentialValidationOptions, fail_fast: FailFast| {
+    let mut units: Vec<(ValidationUnit, bool)> = Vec::new();
+    let result = JWT_CREDENTIAL_VALIDATOR_ED25519.validate_with_progress::<_, Object, _>(
+      &jwt,
+      &issuer_doc,
+      options,
+      fail_fast,
+      |unit, result| units.push((unit, result.is_ok())),
+    );
+    (result, units)
+  };
+
+  // Every unit is reported once, in order.
+  let options = JwtCredentialValidationOptions::default()
+    .latest_issuance_date(issuance_date)
+    .earliest_expiry_date(expiration_date);
+  let (result, units) = validate(&options, FailFast::FirstError);
+  assert!(result.is_ok());
+  assert_eq!(
+    units,
+    [
+      (ValidationUnit::Signature, true),
+      (ValidationUnit::IssuanceDate, true),
+      (ValidationUnit::ExpirationDate, true),
+      (ValidationUnit::Structure, true),
+      (ValidationUnit::SubjectHolderRelationship, true),
+      (ValidationUnit::Status, true),
+    ]
+  );
+
+  // Units after the first failure do not run.
+  let options = options.earliest_expiry_date(expiration_date.checked_add(Duration::seconds(1)).unwrap());
+  let (result, units) = validate(&options, FailFast::FirstError);
+  assert!(matches!(
+    result.unwrap_err().validation_errors.as_slice(),
+    [JwtValidationError::ExpirationDate]
+  ));
+  assert_eq!(
+    units,
+    [
+      (ValidationUnit::Signature, true),
+      (ValidationUnit::IssuanceDate, true),
+      (ValidationUnit::ExpirationDate, false),
+    ]
+  );
+
+  // A failing signature is reported before the result is returned.
+  let (result, units) = validate(
+    &options.verification_options(JwsVerificationOptions::default().nonce("unexpected")),
+    FailFast::AllErrors,
+  );
+  assert!(result.is_err());
+  assert_eq!(units, [(ValidationUnit::Signature, false)]);
+}
+
+#[tokio::test]
+async fn validation_progress() {
+  validation_progress_impl(test_utils::setup_coredocument(None, None).await).await;
+  validation_progress_impl(test_utils::setup_iotadocument(None, None).await).await;
+}
+
 async fn matches_issuer_did_unrelated_issuer_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
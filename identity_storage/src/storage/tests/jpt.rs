@@ -0,0 +1,1307 @@
+// Copyright 2020-2024 IOTA Stiftung, Fondazione Links
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use identity_core::common::Duration;
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Issuer;
+use identity_credential::credential::Jpt;
+use identity_credential::credential::JwpCredentialOptions;
+use identity_credential::credential::RevocationBitmapStatus;
+use identity_credential::presentation::JwpPresentationOptions;
+use identity_credential::presentation::PresentationRequest;
+use identity_credential::presentation::SelectiveDisclosurePresentation;
+use identity_credential::revocation::status_list_2021::StatusList2021;
+use identity_credential::revocation::status_list_2021::StatusList2021Credential;
+use identity_credential::revocation::status_list_2021::StatusList2021CredentialBuilder;
+use identity_credential::revocation::status_list_2021::StatusPurpose;
+use identity_credential::revocation::RevocationBitmap;
+use identity_credential::validator::DecodedJptCredential;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JptCredentialValidationOptions;
+use identity_credential::validator::JptCredentialValidator;
+use identity_credential::validator::JptPresentationValidationOptions;
+use identity_credential::validator::JptPresentationValidator;
+use identity_credential::validator::JptValidationWarning;
+use identity_credential::validator::JwtValidationError;
+use identity_credential::validator::SignerContext;
+use identity_credential::validator::ValidationUnit;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwpVerificationOptions;
+use identity_verification::jwu::decode_b64;
+use identity_verification::jwu::encode_b64;
+use jsonprooftoken::encoding::SerializationType;
+use jsonprooftoken::jpa::algs::ProofAlgorithm;
+use jsonprooftoken::jpt::claims::JptClaims;
+use serde_json::json;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::span::Id;
+use tracing::span::Record;
+use tracing::Event;
+use tracing::Metadata;
+use tracing::Subscriber;
+
+use crate::estimate_jpt_size;
+use crate::storage::tests::test_utils;
+use crate::storage::tests::test_utils::create_jpt_presentation;
+use crate::storage::tests::test_utils::issue_jpt;
+use crate::storage::tests::test_utils::issue_jpt_with;
+use crate::storage::tests::test_utils::present_jpt;
+use crate::storage::tests::test_utils::present_jpt_concealing;
+use crate::storage::tests::test_utils::setup_jpt;
+use crate::storage::tests::test_utils::validate_jpt;
+use crate::storage::tests::test_utils::validate_jpt_presentation;
+use crate::storage::tests::test_utils::CredentialSetup;
+use crate::storage::tests::test_utils::SUBJECT_DOCUMENT_JSON;
+use crate::storage::JwkStorageDocumentError;
+use crate::JwpDocumentExt;
+
+#[tokio::test]
+async fn jpt_issue_and_validate() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(decoded.credential, setup.credential);
+  assert!(decoded.custom_claims.is_none());
+
+  // The kid identifies the issuing method.
+  let method_id: DIDUrl = setup
+    .issuer_doc
+    .resolve_method(setup.fragment.as_str(), None)
+    .unwrap()
+    .id()
+    .clone();
+  assert_eq!(
+    decoded.decoded_jwp.get_issuer_protected_header().kid(),
+    Some(&method_id.to_string())
+  );
+}
+
+#[tokio::test]
+async fn jpt_issuer_as_object() {
+  let mut setup = setup_jpt().await;
+  setup.credential.issuer = serde_json::from_value(json!({
+    "id": setup.issuer_doc.id().to_string(),
+    "name": "Example University"
+  }))
+  .unwrap();
+  let jpt: Jpt = issue_jpt(&setup).await;
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(decoded.credential.issuer, setup.credential.issuer);
+  assert_eq!(decoded.credential.issuer.url().as_str(), setup.issuer_doc.id().as_str());
+}
+
+#[tokio::test]
+async fn jpt_size_estimate() {
+  let setup = setup_jpt().await;
+  let kid: String = setup
+    .issuer_doc
+    .resolve_method(setup.fragment.as_str(), None)
+    .unwrap()
+    .id()
+    .to_string();
+  let mut custom_claims: Object = Object::new();
+  custom_claims.insert("nationality".to_owned(), "IT".into());
+
+  let jpt: Jpt = issue_jpt_with(
+    &setup,
+    &setup.credential,
+    &JwpCredentialOptions::default(),
+    Some(custom_claims.clone()),
+  )
+  .await;
+  let estimate: usize = estimate_jpt_size(
+    &setup.credential,
+    ProofAlgorithm::BLS12381_SHA256,
+    &kid,
+    Some(custom_claims),
+  )
+  .unwrap();
+
+  // Only the length of the proof is estimated.
+  let tolerance: usize = jpt.as_str().len() / 20;
+  assert!(
+    estimate.abs_diff(jpt.as_str().len()) <= tolerance,
+    "estimated {estimate} bytes, issued {} bytes",
+    jpt.as_str().len()
+  );
+}
+
+#[tokio::test]
+async fn jpt_verify_proof_only() {
+  let mut setup = setup_jpt().await;
+  setup.credential.expiration_date = Some(Timestamp::now_utc().checked_sub(Duration::days(1)).unwrap());
+  let jpt: Jpt = issue_jpt(&setup).await;
+
+  // The full pipeline rejects the expired credential.
+  assert!(JptCredentialValidator::validate::<_, Object>(
+    &jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .is_err());
+
+  // Verifying the proof alone leaves the expiration check to the caller.
+  let decoded =
+    JptCredentialValidator::verify_proof_only::<_, Object>(&jpt, &setup.issuer_doc, &JwpVerificationOptions::default())
+      .unwrap();
+  assert_eq!(decoded.credential, setup.credential);
+
+  // A proof from another issuer is still rejected.
+  assert!(JptCredentialValidator::verify_proof_only::<_, Object>(
+    &jpt,
+    &CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap(),
+    &JwpVerificationOptions::default(),
+  )
+  .is_err());
+}
+
+#[tokio::test]
+async fn jpt_validation_progress() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let validate = |options: &JptCredentialValidationOptions| {
+    let mut units: Vec<(ValidationUnit, bool)> = Vec::new();
+    let result = JptCredentialValidator::validate_with_progress::<_, Object, _>(
+      &jpt,
+      &setup.issuer_doc,
+      options,
+      FailFast::AllErrors,
+      |unit, result| units.push((unit, result.is_ok())),
+    );
+    (result, units)
+  };
+
+  let (result, units) = validate(&JptCredentialValidationOptions::default());
+  assert!(result.is_ok());
+  assert_eq!(
+    units,
+    [
+      (ValidationUnit::Signature, true),
+      (ValidationUnit::IssuanceDate, true),
+      (ValidationUnit::ExpirationDate, true),
+      (ValidationUnit::Structure, true),
+      (ValidationUnit::SubjectHolderRelationship, true),
+    ]
+  );
+
+  // The proof is verified first; the other units only run on a valid proof.
+  let options = JptCredentialValidationOptions::default().allowed_proof_algs([ProofAlgorithm::BLS12381_SHAKE256]);
+  let (result, units) = validate(&options);
+  assert!(result.is_err());
+  assert_eq!(units, [(ValidationUnit::Signature, false)]);
+}
+
+#[tokio::test]
+async fn jpt_allowed_proof_algs() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let validate = |options: &JptCredentialValidationOptions| {
+    JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, options, FailFast::FirstError)
+  };
+
+  assert!(validate(
+    &JptCredentialValidationOptions::default()
+      .allowed_proof_algs([ProofAlgorithm::BLS12381_SHA256, ProofAlgorithm::BLS12381_SHAKE256])
+  )
+  .is_ok());
+
+  // The proof is valid, but its suite is not allowed.
+  let options = JptCredentialValidationOptions::default().allowed_proof_algs([ProofAlgorithm::BLS12381_SHAKE256]);
+  assert!(matches!(
+    validate(&options).unwrap_err().validation_errors.as_slice(),
+    [JwtValidationError::JwpProofAlgorithmNotAllowed]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_with_padded_segment_is_rejected() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+
+  assert!(JptCredentialValidator::validate::<_, Object>(
+    &jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .is_ok());
+
+  // Pad the protected header segment.
+  let (header, rest) = jpt.as_str().split_once('.').unwrap();
+  let padded_jpt = Jpt::new(format!("{header}=.{rest}"));
+
+  let error = JptCredentialValidator::validate::<_, Object>(
+    &padded_jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::JwpDecodingError(_)]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_in_json_serialization_is_reported() {
+  let setup = setup_jpt().await;
+  let credential_jpt: Jpt = issue_jpt(&setup).await;
+  let json_credential_jpt = Jpt::new(
+    validate_jpt(&setup, &credential_jpt)
+      .decoded_jwp
+      .encode(SerializationType::JSON)
+      .unwrap(),
+  );
+
+  let error = JptCredentialValidator::validate::<_, Object>(
+    &json_credential_jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::JwpUnsupportedSerialization]
+  ));
+
+  let error = JptPresentationValidator::validate::<_, Object>(
+    &json_credential_jpt,
+    &setup.issuer_doc,
+    &JptPresentationValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::JwpUnsupportedSerialization]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_in_compact_serialization() {
+  let setup = setup_jpt().await;
+  let credential_jpt: Jpt = issue_jpt(&setup).await;
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default()).await;
+
+  let credential_options = JptCredentialValidationOptions::default().serialization(SerializationType::COMPACT);
+  assert!(JptCredentialValidator::validate::<_, Object>(
+    &credential_jpt,
+    &setup.issuer_doc,
+    &credential_options,
+    FailFast::FirstError,
+  )
+  .is_ok());
+  let presentation_options = JptPresentationValidationOptions::default().serialization(SerializationType::COMPACT);
+  validate_jpt_presentation(&setup, &presentation_jpt, &presentation_options);
+
+  // A compact JPT is rejected when the JSON serialization is expected.
+  let error = JptCredentialValidator::validate::<_, Object>(
+    &credential_jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default().serialization(SerializationType::JSON),
+    FailFast::FirstError,
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::JwpUnsupportedSerialization]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_in_json_serialization() {
+  let setup = setup_jpt().await;
+  let credential_jpt: Jpt = issue_jpt(&setup).await;
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default()).await;
+
+  let json_credential_jpt = Jpt::new(
+    validate_jpt(&setup, &credential_jpt)
+      .decoded_jwp
+      .encode(SerializationType::JSON)
+      .unwrap(),
+  );
+  let decoded = JptCredentialValidator::validate::<_, Object>(
+    &json_credential_jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default().serialization(SerializationType::JSON),
+    FailFast::FirstError,
+  )
+  .unwrap();
+  assert_eq!(decoded.credential, setup.credential);
+
+  let json_presentation_jpt = Jpt::new(
+    validate_jpt_presentation(&setup, &presentation_jpt, &JptPresentationValidationOptions::default())
+      .decoded_jwp
+      .encode(SerializationType::JSON)
+      .unwrap(),
+  );
+  let decoded = validate_jpt_presentation(
+    &setup,
+    &json_presentation_jpt,
+    &JptPresentationValidationOptions::default().serialization(SerializationType::JSON),
+  );
+  assert_eq!(decoded.credential.issuer, setup.credential.issuer);
+}
+
+#[tokio::test]
+async fn jpt_with_detached_payloads() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+
+  // Detach the payloads.
+  let segments: Vec<&str> = jpt.as_str().split('.').collect();
+  let [header, payloads, proof] = segments.as_slice() else {
+    panic!("expected a compact JPT");
+  };
+  let payloads: Vec<Vec<u8>> = payloads
+    .split('~')
+    .map(|payload| decode_b64(payload).unwrap())
+    .collect();
+  let detached_jpt = Jpt::new(format!("{header}..{proof}"));
+
+  let decoded = JptCredentialValidator::validate_detached::<_, Object>(
+    &detached_jpt,
+    &payloads,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap();
+  assert_eq!(decoded.credential, setup.credential);
+
+  // A missing payload is detected before verifying the proof.
+  let error = JptCredentialValidator::validate_detached::<_, Object>(
+    &detached_jpt,
+    &payloads[1..],
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::JwpPayloadsMismatch]
+  ));
+
+  // Tampered payloads fail proof verification.
+  let mut tampered_payloads = payloads.clone();
+  tampered_payloads.swap(0, 1);
+  assert!(JptCredentialValidator::validate_detached::<_, Object>(
+    &detached_jpt,
+    &tampered_payloads,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .is_err());
+}
+
+#[tokio::test]
+async fn jpt_with_cnf_claim() {
+  let setup = setup_jpt().await;
+  let cnf: Object = Object::from_json_value(json!({
+    "jwk": {
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }
+  }))
+  .unwrap();
+  let mut custom_claims: Object = Object::new();
+  custom_claims.insert("cnf".to_owned(), cnf.clone().into());
+
+  let jpt: Jpt = issue_jpt_with(
+    &setup,
+    &setup.credential,
+    &JwpCredentialOptions::default(),
+    Some(custom_claims),
+  )
+  .await;
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(decoded.cnf(), Some(&cnf));
+
+  let decoded = validate_jpt(&setup, &issue_jpt(&setup).await);
+  assert!(decoded.cnf().is_none());
+}
+
+#[tokio::test]
+async fn jpt_issuance_skew() {
+  let setup = setup_jpt().await;
+  let issuance_date: Timestamp = Timestamp::now_utc().checked_add(Duration::seconds(30)).unwrap();
+  let expiration_date: Timestamp = Timestamp::now_utc().checked_add(Duration::days(365)).unwrap();
+  let CredentialSetup { credential, .. } = test_utils::generate_credential(
+    &setup.issuer_doc,
+    &[&setup.issuer_doc],
+    Some(issuance_date),
+    Some(expiration_date),
+  );
+  let jpt: Jpt = issue_jpt_with(&setup, &credential, &JwpCredentialOptions::default(), None).await;
+
+  let validate = |options: JptCredentialValidationOptions| {
+    JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, &options, FailFast::FirstError)
+  };
+
+  // Issued in the future without any tolerance.
+  let error = validate(JptCredentialValidationOptions::default()).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::IssuanceDate]
+  ));
+
+  // Within the tolerance.
+  assert!(validate(JptCredentialValidationOptions::default().issuance_skew(Duration::minutes(5))).is_ok());
+
+  // Beyond the tolerance.
+  let error = validate(JptCredentialValidationOptions::default().issuance_skew(Duration::seconds(5))).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::IssuanceDate]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_presentation_exposes_disclosed_claims_only() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let decoded = validate_jpt(&setup, &jpt);
+
+  let mut presentation = SelectiveDisclosurePresentation::new(&decoded.decoded_jwp);
+  presentation.conceal_in_subject("degree.name").unwrap();
+  let presentation_jpt: Jpt = create_jpt_presentation(
+    &setup,
+    &decoded,
+    &mut presentation,
+    &JwpPresentationOptions::default().nonce("0123456789"),
+  )
+  .await;
+
+  let decoded_presentation = validate_jpt_presentation(
+    &setup,
+    &presentation_jpt,
+    &JptPresentationValidationOptions::default().nonce("0123456789"),
+  );
+
+  let degree: &Object = decoded_presentation.disclosed_claims["vc"]["credentialSubject"]["degree"]
+    .as_object()
+    .unwrap();
+  assert_eq!(degree["type"], "BachelorDegree");
+  assert!(!degree.contains_key("name"));
+}
+
+#[tokio::test]
+async fn jpt_conceal_nested_claim() {
+  let mut setup = setup_jpt().await;
+  let OneOrMany::One(subject) = &mut setup.credential.credential_subject else {
+    panic!("expected a single credential subject");
+  };
+  subject.properties.insert(
+    "address".to_owned(),
+    json!({
+      "street": "Via Pier Carlo Boggio",
+      "location": {
+        "postalCode": "10138",
+        "city": "Torino"
+      }
+    }),
+  );
+  let decoded = validate_jpt(&setup, &issue_jpt(&setup).await);
+
+  let mut presentation = SelectiveDisclosurePresentation::new(&decoded.decoded_jwp);
+  presentation
+    .conceal_in_subject_strict("address.location.postalCode")
+    .unwrap();
+  // Concealing an object whose nested attributes stay disclosed is contradictory.
+  assert!(presentation.conceal_in_subject_strict("address.location").is_err());
+  assert!(presentation.conceal_in_subject_strict("address.country").is_err());
+  // The lenient variant ignores paths it cannot conceal.
+  assert!(presentation.conceal_in_subject("address.country").is_ok());
+
+  let presentation_jpt: Jpt =
+    create_jpt_presentation(&setup, &decoded, &mut presentation, &JwpPresentationOptions::default()).await;
+  let decoded_presentation =
+    validate_jpt_presentation(&setup, &presentation_jpt, &JptPresentationValidationOptions::default());
+
+  let address: &Value = &decoded_presentation.disclosed_claims["vc"]["credentialSubject"]["address"];
+  assert_eq!(address["street"], "Via Pier Carlo Boggio");
+  assert_eq!(address["location"], json!({ "city": "Torino" }));
+}
+
+#[tokio::test]
+async fn jpt_validate_presentations() {
+  let setup = setup_jpt().await;
+  let decoded = validate_jpt(&setup, &issue_jpt(&setup).await);
+
+  let mut presentation_jpts: Vec<Jpt> = Vec::new();
+  for nonce in ["0123456789", "9876543210"] {
+    let mut presentation = SelectiveDisclosurePresentation::new(&decoded.decoded_jwp);
+    let presentation_jpt: Jpt = create_jpt_presentation(
+      &setup,
+      &decoded,
+      &mut presentation,
+      &JwpPresentationOptions::default().nonce(nonce),
+    )
+    .await;
+    presentation_jpts.push(presentation_jpt);
+  }
+
+  let other_issuer_doc = CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap();
+  let results = JptPresentationValidator::validate_presentations::<_, Object>(
+    &presentation_jpts,
+    &[other_issuer_doc, setup.issuer_doc.clone()],
+    &JptPresentationValidationOptions::default().nonce("0123456789"),
+    FailFast::FirstError,
+  );
+
+  // Each presentation is validated on its own: only the second one carries an unexpected nonce.
+  assert_eq!(results.len(), 2);
+  assert!(results[0].is_ok());
+  assert!(results[1].is_err());
+
+  // Presentations of untrusted issuers are rejected.
+  let results = JptPresentationValidator::validate_presentations::<_, Object>(
+    &presentation_jpts[..1],
+    &[CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap()],
+    &JptPresentationValidationOptions::default().nonce("0123456789"),
+    FailFast::FirstError,
+  );
+  assert!(matches!(
+    results[0].as_ref().unwrap_err().validation_errors.as_slice(),
+    [JwtValidationError::DocumentMismatch(SignerContext::Issuer)]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_errors_report_issuer_context() {
+  let setup = setup_jpt().await;
+  let other_issuer_doc = CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap();
+
+  // JPT presentations carry no holder proof, so all failures are attributed to the issuer.
+  let errors = JptCredentialValidator::validate::<_, Object>(
+    &issue_jpt(&setup).await,
+    &other_issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err()
+  .validation_errors;
+  assert!(matches!(
+    errors.as_slice(),
+    [JwtValidationError::DocumentMismatch(SignerContext::Issuer)]
+  ));
+
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default()).await;
+  let errors = JptPresentationValidator::validate::<_, Object>(
+    &presentation_jpt,
+    &other_issuer_doc,
+    &JptPresentationValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap_err()
+  .validation_errors;
+  assert!(matches!(
+    errors.as_slice(),
+    [JwtValidationError::DocumentMismatch(SignerContext::Issuer)]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_typed_custom_claims() {
+  #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+  struct CustomClaims {
+    age_over: u32,
+    nationality: String,
+  }
+
+  let setup = setup_jpt().await;
+  let custom_claims: Object = Object::from_json_value(json!({
+    "age_over": 18,
+    "nationality": "IT"
+  }))
+  .unwrap();
+  let jpt: Jpt = issue_jpt_with(
+    &setup,
+    &setup.credential,
+    &JwpCredentialOptions::default(),
+    Some(custom_claims),
+  )
+  .await;
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(
+    decoded.custom_claims_as::<CustomClaims>().unwrap(),
+    Some(CustomClaims {
+      age_over: 18,
+      nationality: "IT".to_owned()
+    })
+  );
+
+  // Custom claims that do not match the expected type.
+  assert!(decoded.custom_claims_as::<Vec<String>>().is_err());
+}
+
+#[tokio::test]
+async fn jpt_require_kid() {
+  let setup = setup_jpt().await;
+  let method_id: DIDUrl = setup
+    .issuer_doc
+    .resolve_method(setup.fragment.as_str(), None)
+    .unwrap()
+    .id()
+    .clone();
+  let options = |require_kid: bool| {
+    JptCredentialValidationOptions::default().verification_options(
+      JwpVerificationOptions::default()
+        .method_id(method_id.clone())
+        .require_kid(require_kid),
+    )
+  };
+
+  for (kid, matches_method) in [
+    (method_id.to_string(), true),
+    (
+      setup.issuer_doc.id().to_url().join("#other").unwrap().to_string(),
+      false,
+    ),
+    ("not a did url".to_owned(), false),
+  ] {
+    let jpt: Jpt = issue_jpt_with(
+      &setup,
+      &setup.credential,
+      &JwpCredentialOptions::default().kid(kid),
+      None,
+    )
+    .await;
+
+    // The explicit method id takes precedence over the kid.
+    assert!(JptCredentialValidator::validate::<_, Object>(
+      &jpt,
+      &setup.issuer_doc,
+      &options(false),
+      FailFast::FirstError
+    )
+    .is_ok());
+
+    let result =
+      JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, &options(true), FailFast::FirstError);
+    if matches_method {
+      assert!(result.is_ok());
+    } else {
+      assert!(matches!(
+        result.unwrap_err().validation_errors.as_slice(),
+        [JwtValidationError::MethodDataLookupError { .. }]
+      ));
+    }
+  }
+}
+
+#[tokio::test]
+async fn jpt_to_vc_json() {
+  let setup = setup_jpt().await;
+  let decoded = validate_jpt(&setup, &issue_jpt(&setup).await);
+  assert_eq!(decoded.to_vc_json().unwrap(), setup.credential.to_json_value().unwrap());
+
+  // A presentation conceals the id and dates by default, but nothing else.
+  let mut presentation = SelectiveDisclosurePresentation::new(&decoded.decoded_jwp);
+  let presentation_jpt: Jpt =
+    create_jpt_presentation(&setup, &decoded, &mut presentation, &JwpPresentationOptions::default()).await;
+  let decoded_presentation =
+    validate_jpt_presentation(&setup, &presentation_jpt, &JptPresentationValidationOptions::default());
+  let mut expected: Value = setup.credential.to_json_value().unwrap();
+  for concealed in ["id", "issuanceDate", "expirationDate"] {
+    expected.as_object_mut().unwrap().remove(concealed);
+  }
+  assert_eq!(decoded_presentation.to_vc_json().unwrap(), expected);
+}
+
+#[tokio::test]
+async fn jpt_inconsistent_expiration() {
+  let setup = setup_jpt().await;
+  let issue_with_exp = |exp: Timestamp| {
+    // Claims of a foreign issuer setting both the JWT `exp` and the credential's `expirationDate`.
+    let mut vc: Value = setup.credential.to_json_value().unwrap();
+    vc.as_object_mut().unwrap().remove("id");
+    vc["credentialSubject"].as_object_mut().unwrap().remove("id");
+    let mut claims = JptClaims::new();
+    claims.set_iss(setup.issuer_doc.id().to_string());
+    claims.set_nbf(setup.credential.issuance_date.to_unix());
+    claims.set_exp(exp.to_unix());
+    claims.set_claim(Some("vc"), vc, true);
+    let setup = &setup;
+    async move {
+      Jpt::new(
+        setup
+          .issuer_doc
+          .create_issued_jwp(
+            &setup.storage,
+            &setup.fragment,
+            &claims,
+            &JwpCredentialOptions::default(),
+          )
+          .await
+          .unwrap(),
+      )
+    }
+  };
+  let validate = |jpt: &Jpt| {
+    JptCredentialValidator::validate::<_, Object>(
+      jpt,
+      &setup.issuer_doc,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  };
+
+  let expiration_date: Timestamp = setup.credential.expiration_date.unwrap();
+  assert!(validate(&issue_with_exp(expiration_date).await).is_ok());
+
+  // `exp` and `expirationDate` disagree.
+  let exp: Timestamp = expiration_date.checked_sub(Duration::days(1)).unwrap();
+  assert!(matches!(
+    validate(&issue_with_exp(exp).await)
+      .unwrap_err()
+      .validation_errors
+      .as_slice(),
+    [JwtValidationError::CredentialStructure(
+      identity_credential::Error::InconsistentCredentialJwtClaims(_)
+    )]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_error_categories() {
+  let setup = setup_jpt().await;
+  let validate = |jpt: &Jpt| {
+    JptCredentialValidator::validate::<_, Object>(
+      jpt,
+      &setup.issuer_doc,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap_err()
+    .validation_errors
+  };
+
+  // Malformed input.
+  let errors = validate(&Jpt::new("not.a.jpt".to_owned()));
+  assert!(matches!(errors.as_slice(), [JwtValidationError::JwpDecodingError(_)]));
+
+  // Tampered payloads.
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let segments: Vec<&str> = jpt.as_str().split('.').collect();
+  let [header, payloads, proof] = segments.as_slice() else {
+    panic!("expected a compact JPT");
+  };
+  let mut payloads: Vec<&str> = payloads.split('~').collect();
+  payloads.swap(0, 1);
+  let tampered_jpt = Jpt::new(format!("{header}.{}.{proof}", payloads.join("~")));
+  let errors = validate(&tampered_jpt);
+  assert!(matches!(
+    errors.as_slice(),
+    [JwtValidationError::JwpProofVerificationError(_)]
+  ));
+
+  // Validly signed claims that are not a credential.
+  let mut claims = JptClaims::new();
+  claims.set_iss(setup.issuer_doc.id().to_string());
+  claims.set_nbf(Timestamp::now_utc().to_unix());
+  claims.set_claim(Some("vc"), json!({ "foo": "bar" }), true);
+  let jpt = Jpt::new(
+    setup
+      .issuer_doc
+      .create_issued_jwp(
+        &setup.storage,
+        &setup.fragment,
+        &claims,
+        &JwpCredentialOptions::default(),
+      )
+      .await
+      .unwrap(),
+  );
+  let errors = validate(&jpt);
+  assert!(matches!(
+    errors.as_slice(),
+    [JwtValidationError::CredentialStructure(_)]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_presentation_accepted_nonces_and_audiences() {
+  let setup = setup_jpt().await;
+  let verifier_a: Url = Url::parse("https://verifier-a.example/").unwrap();
+  let verifier_b: Url = Url::parse("https://verifier-b.example/").unwrap();
+  let options = JptPresentationValidationOptions::default()
+    .nonce("nonce-a")
+    .accept_nonce("nonce-b")
+    .accept_audience(verifier_a.clone())
+    .accept_audience(verifier_b.clone());
+  let validate = |presentation_jpt: &Jpt| {
+    JptPresentationValidator::validate::<_, Object>(presentation_jpt, &setup.issuer_doc, &options, FailFast::FirstError)
+  };
+
+  // Any of the accepted nonces and audiences.
+  for (nonce, audience) in [("nonce-a", &verifier_a), ("nonce-b", &verifier_b)] {
+    let presentation_jpt: Jpt = present_jpt(
+      &setup,
+      &JwpPresentationOptions::default()
+        .nonce(nonce)
+        .audience(audience.clone()),
+    )
+    .await;
+    assert!(validate(&presentation_jpt).is_ok());
+  }
+
+  // Unknown nonce.
+  let presentation_jpt: Jpt = present_jpt(
+    &setup,
+    &JwpPresentationOptions::default()
+      .nonce("nonce-c")
+      .audience(verifier_a.clone()),
+  )
+  .await;
+  assert!(validate(&presentation_jpt).is_err());
+
+  // Unknown or missing audience.
+  let presentation_jpt: Jpt = present_jpt(
+    &setup,
+    &JwpPresentationOptions::default()
+      .nonce("nonce-a")
+      .audience(Url::parse("https://verifier-c.example/").unwrap()),
+  )
+  .await;
+  assert!(validate(&presentation_jpt).is_err());
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default().nonce("nonce-a")).await;
+  assert!(validate(&presentation_jpt).is_err());
+
+  // Without accepted nonces, the presentation must not carry one.
+  assert!(JptPresentationValidator::validate::<_, Object>(
+    &presentation_jpt,
+    &setup.issuer_doc,
+    &JptPresentationValidationOptions::default(),
+    FailFast::FirstError
+  )
+  .is_err());
+}
+
+#[tokio::test]
+async fn jpt_presentation_challenge_max_age() {
+  let setup = setup_jpt().await;
+  let request = PresentationRequest::new(["degree.type"]);
+  let presentation_jpt: Jpt = present_jpt(&setup, &request.presentation_options()).await;
+  let validate = |options: JptPresentationValidationOptions| {
+    JptPresentationValidator::validate::<_, Object>(
+      &presentation_jpt,
+      &setup.issuer_doc,
+      &options,
+      FailFast::FirstError,
+    )
+  };
+
+  assert!(validate(request.validation_options().challenge_max_age(Duration::minutes(5))).is_ok());
+
+  // The challenge is older than the window.
+  let stale_options = request
+    .validation_options()
+    .challenge_issued_at(Timestamp::now_utc().checked_sub(Duration::minutes(10)).unwrap())
+    .challenge_max_age(Duration::minutes(5));
+  assert!(validate(stale_options).is_err());
+}
+
+#[tokio::test]
+async fn jpt_presentation_request_requires_disclosure() {
+  let setup = setup_jpt().await;
+  let request = PresentationRequest::new(["degree.type", "name"]);
+  let validate = |presentation_jpt: &Jpt| {
+    JptPresentationValidator::validate::<_, Object>(
+      presentation_jpt,
+      &setup.issuer_doc,
+      &request.validation_options(),
+      FailFast::FirstError,
+    )
+  };
+
+  let presentation_jpt: Jpt = present_jpt_concealing(&setup, &request.presentation_options(), &["degree.name"]).await;
+  assert!(validate(&presentation_jpt).is_ok());
+
+  // The holder withholds a requested claim.
+  let presentation_jpt: Jpt = present_jpt_concealing(&setup, &request.presentation_options(), &["degree.type"]).await;
+  let error = validate(&presentation_jpt).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::UndisclosedClaim(path)] if path == "degree.type"
+  ));
+}
+
+#[tokio::test]
+async fn jpt_claim_names_follow_payload_order() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let decoded = validate_jpt(&setup, &jpt);
+
+  // The order of the claims in the issuer protected header.
+  let segments: Vec<&str> = jpt.as_str().split('.').collect();
+  let header: Value = serde_json::from_slice(&decode_b64(segments[0]).unwrap()).unwrap();
+  let issued_claim_names: Vec<String> = serde_json::from_value(header["claims"].clone()).unwrap();
+  let claim_names: Vec<String> = decoded.claim_names();
+  assert_eq!(claim_names, issued_claim_names);
+
+  // The payload at the index of a claim carries its value.
+  let payloads: Vec<&str> = segments[1].split('~').collect();
+  assert_eq!(payloads.len(), claim_names.len());
+  let iss_index: usize = claim_names.iter().position(|name| name == "iss").unwrap();
+  let iss: Value = serde_json::from_slice(&decode_b64(payloads[iss_index]).unwrap()).unwrap();
+  assert_eq!(iss, setup.issuer_doc.id().to_string());
+
+  // Presentations keep the positions of concealed claims.
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default()).await;
+  let decoded_presentation =
+    validate_jpt_presentation(&setup, &presentation_jpt, &JptPresentationValidationOptions::default());
+  assert_eq!(decoded_presentation.claim_names(), claim_names);
+}
+
+#[tokio::test]
+async fn jpt_near_expiry_warning() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let validate = |options: &JptCredentialValidationOptions| {
+    JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, options, FailFast::AllErrors)
+  };
+
+  // The credential expires in a year.
+  let decoded = validate(&JptCredentialValidationOptions::default().near_expiry_period(Duration::days(30))).unwrap();
+  assert!(decoded.warnings.is_empty());
+
+  let decoded = validate(&JptCredentialValidationOptions::default().near_expiry_period(Duration::days(400))).unwrap();
+  assert_eq!(decoded.warnings, [JptValidationWarning::NearExpiry]);
+
+  let error = validate(
+    &JptCredentialValidationOptions::default()
+      .near_expiry_period(Duration::days(400))
+      .promote_warning(JptValidationWarning::NearExpiry),
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::PromotedWarning(JptValidationWarning::NearExpiry)]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_missing_expiration_date() {
+  let mut setup = setup_jpt().await;
+  setup.credential.expiration_date = None;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let validate = |options: &JptCredentialValidationOptions| {
+    JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, options, FailFast::AllErrors)
+  };
+
+  assert!(validate(&JptCredentialValidationOptions::default()).is_ok());
+
+  let error = validate(&JptCredentialValidationOptions::default().require_expiry(true)).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::MissingExpirationDate]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_kid_as_jwk_thumbprint() {
+  let setup = setup_jpt().await;
+  let thumbprint: String = setup
+    .issuer_doc
+    .resolve_method(setup.fragment.as_str(), None)
+    .unwrap()
+    .data()
+    .public_key_jwk()
+    .unwrap()
+    .thumbprint_sha256_b64();
+
+  let issue_with_kid = |kid: String| {
+    setup.issuer_doc.create_credential_jpt(
+      &setup.credential,
+      &setup.storage,
+      &setup.fragment,
+      &JwpCredentialOptions::new().kid(kid),
+      None,
+    )
+  };
+  let validate = |jpt: &Jpt| {
+    JptCredentialValidator::validate::<_, Object>(
+      jpt,
+      &setup.issuer_doc,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  };
+
+  let jpt: Jpt = issue_with_kid(thumbprint).await.unwrap();
+  let decoded = validate(&jpt).unwrap();
+  assert_eq!(decoded.credential, setup.credential);
+
+  // A kid that neither is a DID Url nor matches a method's thumbprint.
+  let jpt: Jpt = issue_with_kid("unknown-key".to_owned()).await.unwrap();
+  let error = validate(&jpt).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::MethodDataLookupError { .. }]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_status_list_2021() {
+  let setup = setup_jpt().await;
+  let mut status_list_credential: StatusList2021Credential =
+    StatusList2021CredentialBuilder::new(StatusList2021::default())
+      .purpose(StatusPurpose::Revocation)
+      .subject_id(Url::parse("https://example.com/credentials/status").unwrap())
+      .issuer(Issuer::Url(setup.issuer_doc.id().to_url().into()))
+      .build()
+      .unwrap();
+
+  let index: usize = 42;
+  let mut credential: Credential = setup.credential.clone();
+  status_list_credential
+    .set_credential_status(&mut credential, index, false)
+    .unwrap();
+  let jpt: Jpt = issue_jpt_with(&setup, &credential, &JwpCredentialOptions::default(), None).await;
+  let validate = |status_list_credential: &StatusList2021Credential| {
+    JptCredentialValidator::validate_with_status_list_2021::<_, Object>(
+      &jpt,
+      &setup.issuer_doc,
+      status_list_credential,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  };
+
+  let decoded = validate(&status_list_credential).unwrap();
+  assert_eq!(decoded.credential, credential);
+
+  status_list_credential.set_entry(index, true).unwrap();
+  let error = validate(&status_list_credential).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::Revoked]
+  ));
+}
+
+#[tokio::test]
+async fn jpt_claims_transform() {
+  let setup = setup_jpt().await;
+
+  // The issuer stores the subject's name base64url encoded.
+  let mut credential: Credential = setup.credential.clone();
+  let OneOrMany::One(subject) = &mut credential.credential_subject else {
+    panic!("expected a single credential subject");
+  };
+  subject
+    .properties
+    .insert("name".to_owned(), Value::String(encode_b64("Alice")));
+  let jpt: Jpt = issue_jpt_with(&setup, &credential, &JwpCredentialOptions::default(), None).await;
+  let subject_name =
+    |decoded: &DecodedJptCredential| decoded.credential.credential_subject.first().unwrap().properties["name"].clone();
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(subject_name(&decoded), encode_b64("Alice"));
+
+  let decode_name = |claims: &mut Object| -> Result<(), identity_credential::Error> {
+    let name: &mut Value = claims
+      .get_mut("vc")
+      .and_then(|vc| vc.get_mut("credentialSubject"))
+      .and_then(|subject| subject.get_mut("name"))
+      .ok_or_else(|| identity_credential::Error::JptClaimsSetDeserializationError("missing name".into()))?;
+    let decoded_name: Vec<u8> = name
+      .as_str()
+      .and_then(|name| decode_b64(name).ok())
+      .ok_or_else(|| identity_credential::Error::JptClaimsSetDeserializationError("invalid name".into()))?;
+    *name = Value::String(String::from_utf8(decoded_name).unwrap());
+    Ok(())
+  };
+  let decoded = JptCredentialValidator::validate_with_claims_transform::<_, Object, _>(
+    &jpt,
+    &setup.issuer_doc,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+    decode_name,
+  )
+  .unwrap();
+  assert_eq!(subject_name(&decoded), "Alice");
+}
+
+#[tokio::test]
+async fn jpt_assign_revocation_bitmap_status() {
+  let mut setup = setup_jpt().await;
+  let options = JwpCredentialOptions::new().revocation_bitmap_status("revocation", 5);
+  assert_eq!(
+    options.to_json_value().unwrap()["revocationBitmapStatus"],
+    json!({ "serviceFragment": "revocation", "index": 5 })
+  );
+
+  // The issuer document must contain the referenced service.
+  let error = setup
+    .issuer_doc
+    .create_credential_jpt(&setup.credential, &setup.storage, &setup.fragment, &options, None)
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    error,
+    JwkStorageDocumentError::InvalidRevocationBitmapService(_)
+  ));
+
+  let service_id: DIDUrl = setup.issuer_doc.id().to_url().join("#revocation").unwrap();
+  setup
+    .issuer_doc
+    .insert_service(RevocationBitmap::new().to_service(service_id.clone()).unwrap())
+    .unwrap();
+  let jpt: Jpt = issue_jpt_with(&setup, &setup.credential, &options, None).await;
+
+  let decoded = validate_jpt(&setup, &jpt);
+  assert_eq!(
+    decoded.credential.credential_status,
+    Some(RevocationBitmapStatus::new(service_id, 5).into())
+  );
+}
+
+/// A span emitted by the JPT validators, identified by its `kind` or `stage` field.
+#[derive(Debug, PartialEq, Eq)]
+struct RecordedSpan {
+  label: String,
+  success: Option<bool>,
+}
+
+impl RecordedSpan {
+  fn new(label: &str, success: Option<bool>) -> Self {
+    Self {
+      label: label.to_owned(),
+      success,
+    }
+  }
+}
+
+impl Visit for RecordedSpan {
+  fn record_str(&mut self, field: &Field, value: &str) {
+    if matches!(field.name(), "kind" | "stage") {
+      self.label = value.to_owned();
+    }
+  }
+
+  fn record_bool(&mut self, field: &Field, value: bool) {
+    if field.name() == "success" {
+      self.success = Some(value);
+    }
+  }
+
+  fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// A subscriber recording spans in the order in which they are created.
+#[derive(Clone, Default)]
+struct SpanRecorder(Arc<Mutex<Vec<RecordedSpan>>>);
+
+impl SpanRecorder {
+  fn take(&self) -> Vec<RecordedSpan> {
+    std::mem::take(&mut self.0.lock().unwrap())
+  }
+}
+
+impl Subscriber for SpanRecorder {
+  fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+    true
+  }
+
+  fn new_span(&self, span: &Attributes<'_>) -> Id {
+    let mut recorded = RecordedSpan::new(span.metadata().name(), None);
+    span.record(&mut recorded);
+    let mut spans = self.0.lock().unwrap();
+    spans.push(recorded);
+    Id::from_u64(spans.len() as u64)
+  }
+
+  fn record(&self, span: &Id, values: &Record<'_>) {
+    if let Some(recorded) = self.0.lock().unwrap().get_mut(span.into_u64() as usize - 1) {
+      values.record(recorded);
+    }
+  }
+
+  fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+  fn event(&self, _event: &Event<'_>) {}
+
+  fn enter(&self, _span: &Id) {}
+
+  fn exit(&self, _span: &Id) {}
+}
+
+#[tokio::test]
+async fn jpt_validation_spans() {
+  let setup = setup_jpt().await;
+  let jpt: Jpt = issue_jpt(&setup).await;
+  let presentation_jpt: Jpt = present_jpt(&setup, &JwpPresentationOptions::default()).await;
+  let recorder = SpanRecorder::default();
+
+  tracing::subscriber::with_default(recorder.clone(), || {
+    validate_jpt(&setup, &jpt);
+  });
+  assert_eq!(
+    recorder.take(),
+    [
+      RecordedSpan::new("credential", None),
+      RecordedSpan::new("decode", Some(true)),
+      RecordedSpan::new("issuer_resolution", Some(true)),
+      RecordedSpan::new("proof_verification", Some(true)),
+      RecordedSpan::new("issuance_date", Some(true)),
+      RecordedSpan::new("expiration_date", Some(true)),
+      RecordedSpan::new("structure", Some(true)),
+      RecordedSpan::new("subject_holder_relationship", Some(true)),
+    ]
+  );
+
+  tracing::subscriber::with_default(recorder.clone(), || {
+    validate_jpt_presentation(&setup, &presentation_jpt, &JptPresentationValidationOptions::default());
+  });
+  assert_eq!(
+    recorder.take(),
+    [
+      RecordedSpan::new("presentation", None),
+      RecordedSpan::new("decode", Some(true)),
+      RecordedSpan::new("issuer_resolution", Some(true)),
+      RecordedSpan::new("proof_verification", Some(true)),
+      RecordedSpan::new("structure", Some(true)),
+      RecordedSpan::new("disclosure", Some(true)),
+    ]
+  );
+
+  // The stages following a failed one do not run.
+  let options = JptCredentialValidationOptions::default().allowed_proof_algs([ProofAlgorithm::BLS12381_SHAKE256]);
+  tracing::subscriber::with_default(recorder.clone(), || {
+    assert!(
+      JptCredentialValidator::validate::<_, Object>(&jpt, &setup.issuer_doc, &options, FailFast::AllErrors).is_err()
+    );
+  });
+  assert_eq!(
+    recorder.take(),
+    [
+      RecordedSpan::new("credential", None),
+      RecordedSpan::new("decode", Some(false)),
+    ]
+  );
+}
@@ -4,6 +4,8 @@
 mod api;
 mod credential_jws;
 mod credential_validation;
+#[cfg(feature = "jpt-bbs-plus")]
+mod jpt;
 mod kb_jwt;
 mod presentation_validation;
 pub(crate) mod test_utils;
@@ -12,13 +12,18 @@ use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_credential::presentation::PresentationBuilder;
 use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
 use identity_credential::validator::JwtPresentationValidationOptions;
 use identity_credential::validator::JwtPresentationValidator;
 use identity_credential::validator::JwtPresentationValidatorUtils;
 use identity_credential::validator::JwtValidationError;
+use identity_credential::validator::SignerContext;
 use identity_did::CoreDID;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_verification::jws::JwsAlgorithm;
 use identity_verification::MethodScope;
@@ -423,6 +428,200 @@ where
   ));
 }
 
+#[tokio::test]
+async fn holder_errors_report_holder_context() {
+  let setup = setup_coredocument(None, None).await;
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  // The holder is not a DID.
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(Url::parse("https://example.com/holder").unwrap(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let validation_error: JwtValidationError = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default(),
+    )
+    .err()
+    .unwrap()
+    .presentation_validation_errors
+    .into_iter()
+    .next()
+    .unwrap();
+  assert!(matches!(
+    validation_error,
+    JwtValidationError::SignerUrl {
+      signer_ctx: SignerContext::Holder,
+      ..
+    }
+  ));
+
+  let extraction_error: JwtValidationError =
+    JwtPresentationValidatorUtils::extract_holder::<CoreDID>(&presentation_jwt).unwrap_err();
+  assert!(matches!(
+    extraction_error,
+    JwtValidationError::SignerUrl {
+      signer_ctx: SignerContext::Holder,
+      ..
+    }
+  ));
+}
+
+#[tokio::test]
+async fn presented_credentials_require_trusted_issuer() {
+  let setup = setup_coredocument(None, None).await;
+  let untrusted_setup = setup_coredocument(None, None).await;
+
+  let trusted_credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let untrusted_credential: CredentialSetup =
+    generate_credential(&untrusted_setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let trusted_jws = sign_credential(&setup, &trusted_credential.credential).await;
+  let untrusted_jws = sign_credential(&untrusted_setup, &untrusted_credential.credential).await;
+
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(trusted_jws)
+      .credential(untrusted_jws)
+      .build()
+      .unwrap();
+
+  let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let results = validator.validate_presented_credentials::<_, Object, Object>(
+    &presentation,
+    std::slice::from_ref(&setup.issuer_doc),
+    &JwtCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  );
+
+  assert_eq!(results.len(), 2);
+  assert!(results[0].is_ok());
+  let validation_errors = &results[1].as_ref().unwrap_err().validation_errors;
+  assert_eq!(validation_errors.len(), 1);
+  assert!(matches!(
+    validation_errors[0],
+    JwtValidationError::DocumentMismatch(SignerContext::Issuer)
+  ));
+}
+
+#[tokio::test]
+async fn holder_binding_only() {
+  let setup = setup_coredocument(None, None).await;
+  // The credential expired in the past.
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default().nonce("challenge"),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  let validate = |nonce: &str| {
+    JWT_PRESENTATION_VALIDATOR_ED25519.validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default()
+        .presentation_verifier_options(JwsVerificationOptions::default().nonce(nonce)),
+    )
+  };
+
+  // Only the holder's proof and the nonce are checked, not the embedded credentials.
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = validate("challenge").unwrap();
+  let credential_error = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+    .validate_presented_credentials::<_, Object, Object>(
+      &decoded_presentation.presentation,
+      std::slice::from_ref(&setup.issuer_doc),
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .remove(0)
+    .unwrap_err();
+  assert!(matches!(
+    credential_error.validation_errors.as_slice(),
+    [JwtValidationError::ExpirationDate]
+  ));
+
+  let validation_error: JwtValidationError = validate("other-challenge")
+    .unwrap_err()
+    .presentation_validation_errors
+    .remove(0);
+  assert!(matches!(validation_error, JwtValidationError::PresentationJwsError(_)));
+}
+
+#[tokio::test]
+async fn presentation_without_credentials() {
+  let setup = setup_coredocument(None, None).await;
+
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .build()
+      .unwrap();
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default().nonce("challenge"),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  let validate = |nonce: &str| {
+    JWT_PRESENTATION_VALIDATOR_ED25519.validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default()
+        .presentation_verifier_options(JwsVerificationOptions::default().nonce(nonce)),
+    )
+  };
+
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = validate("challenge").unwrap();
+  assert!(decoded_presentation.presentation.verifiable_credential.is_empty());
+  assert!(
+    JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+      .validate_presented_credentials::<_, Object, Object>(
+        &decoded_presentation.presentation,
+        std::slice::from_ref(&setup.issuer_doc),
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .is_empty()
+  );
+
+  let validation_error: JwtValidationError = validate("other-challenge")
+    .unwrap_err()
+    .presentation_validation_errors
+    .remove(0);
+  assert!(matches!(validation_error, JwtValidationError::PresentationJwsError(_)));
+}
+
 async fn sign_credential<T>(setup: &Setup<T, T>, credential: &Credential) -> Jwt
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -34,7 +34,7 @@ const ISSUER_DOCUMENT_JSON: &str = r#"
     "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr"
 }"#;
 
-const SUBJECT_DOCUMENT_JSON: &str = r#"
+pub(super) const SUBJECT_DOCUMENT_JSON: &str = r#"
 {
     "id": "did:foo:0xabcdef"
 }"#;
@@ -197,3 +197,152 @@ pub(crate) fn encode_public_ed25519_jwk(public_key: &PublicKey) -> Jwk {
   jwk.set_alg(JwsAlgorithm::EdDSA.name());
   jwk
 }
+
+#[cfg(feature = "jpt-bbs-plus")]
+pub(super) use self::jpt::*;
+
+#[cfg(feature = "jpt-bbs-plus")]
+mod jpt {
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::convert::FromJson;
+  use identity_credential::credential::Credential;
+  use identity_credential::credential::Jpt;
+  use identity_credential::credential::JwpCredentialOptions;
+  use identity_credential::presentation::JwpPresentationOptions;
+  use identity_credential::presentation::SelectiveDisclosurePresentation;
+  use identity_credential::validator::DecodedJptCredential;
+  use identity_credential::validator::DecodedJptPresentation;
+  use identity_credential::validator::FailFast;
+  use identity_credential::validator::JptCredentialValidationOptions;
+  use identity_credential::validator::JptCredentialValidator;
+  use identity_credential::validator::JptPresentationValidationOptions;
+  use identity_credential::validator::JptPresentationValidator;
+  use identity_document::document::CoreDocument;
+  use identity_verification::MethodScope;
+  use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+  use crate::key_id_storage::KeyIdMemstore;
+  use crate::key_storage::JwkMemStore;
+  use crate::JwpDocumentExt;
+
+  use super::CredentialSetup;
+  use super::MemStorage;
+  use super::ISSUER_DOCUMENT_JSON;
+  use super::SUBJECT_DOCUMENT_JSON;
+
+  pub(in crate::storage::tests) struct JptSetup {
+    pub(crate) issuer_doc: CoreDocument,
+    pub(crate) storage: MemStorage,
+    pub(crate) fragment: String,
+    pub(crate) credential: Credential,
+  }
+
+  /// Creates an issuer document with a BLS12-381 method and a credential expiring in a year.
+  pub(in crate::storage::tests) async fn setup_jpt() -> JptSetup {
+    let mut issuer_doc = CoreDocument::from_json(ISSUER_DOCUMENT_JSON).unwrap();
+    let subject_doc = CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap();
+    let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+
+    let fragment: String = issuer_doc
+      .generate_method_jwp(
+        &storage,
+        JwkMemStore::BLS12381G2_KEY_TYPE,
+        ProofAlgorithm::BLS12381_SHA256,
+        None,
+        MethodScope::VerificationMethod,
+      )
+      .await
+      .unwrap();
+
+    let expiration_date: Timestamp = Timestamp::now_utc().checked_add(Duration::days(365)).unwrap();
+    let CredentialSetup { credential, .. } =
+      super::generate_credential(&issuer_doc, &[&subject_doc], None, Some(expiration_date));
+
+    JptSetup {
+      issuer_doc,
+      storage,
+      fragment,
+      credential,
+    }
+  }
+
+  /// Issues the credential of `setup` as a JPT.
+  pub(in crate::storage::tests) async fn issue_jpt(setup: &JptSetup) -> Jpt {
+    issue_jpt_with(setup, &setup.credential, &JwpCredentialOptions::default(), None).await
+  }
+
+  /// Issues `credential` as a JPT with the method of `setup`.
+  pub(in crate::storage::tests) async fn issue_jpt_with(
+    setup: &JptSetup,
+    credential: &Credential,
+    options: &JwpCredentialOptions,
+    custom_claims: Option<Object>,
+  ) -> Jpt {
+    setup
+      .issuer_doc
+      .create_credential_jpt(credential, &setup.storage, &setup.fragment, options, custom_claims)
+      .await
+      .unwrap()
+  }
+
+  /// Validates `jpt` with the default options, expecting it to be valid.
+  pub(in crate::storage::tests) fn validate_jpt(setup: &JptSetup, jpt: &Jpt) -> DecodedJptCredential {
+    JptCredentialValidator::validate::<_, Object>(
+      jpt,
+      &setup.issuer_doc,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap()
+  }
+
+  /// Creates a presentation JPT of the validated credential `decoded`.
+  pub(in crate::storage::tests) async fn create_jpt_presentation(
+    setup: &JptSetup,
+    decoded: &DecodedJptCredential,
+    presentation: &mut SelectiveDisclosurePresentation,
+    options: &JwpPresentationOptions,
+  ) -> Jpt {
+    let method_id: String = decoded
+      .decoded_jwp
+      .get_issuer_protected_header()
+      .kid()
+      .unwrap()
+      .to_owned();
+    setup
+      .issuer_doc
+      .create_presentation_jpt(presentation, &method_id, options)
+      .await
+      .unwrap()
+  }
+
+  /// Presents a freshly issued credential, concealing only the claims concealed by default.
+  pub(in crate::storage::tests) async fn present_jpt(setup: &JptSetup, options: &JwpPresentationOptions) -> Jpt {
+    present_jpt_concealing(setup, options, &[]).await
+  }
+
+  /// Presents a freshly issued credential, concealing the `credentialSubject` attributes at `concealed_paths`.
+  pub(in crate::storage::tests) async fn present_jpt_concealing(
+    setup: &JptSetup,
+    options: &JwpPresentationOptions,
+    concealed_paths: &[&str],
+  ) -> Jpt {
+    let decoded = validate_jpt(setup, &issue_jpt(setup).await);
+    let mut presentation = SelectiveDisclosurePresentation::new(&decoded.decoded_jwp);
+    for path in concealed_paths {
+      presentation.conceal_in_subject_strict(path).unwrap();
+    }
+    create_jpt_presentation(setup, &decoded, &mut presentation, options).await
+  }
+
+  /// Validates the presentation `jpt` with `options`, expecting it to be valid.
+  pub(in crate::storage::tests) fn validate_jpt_presentation(
+    setup: &JptSetup,
+    jpt: &Jpt,
+    options: &JptPresentationValidationOptions,
+  ) -> DecodedJptPresentation {
+    JptPresentationValidator::validate::<_, Object>(jpt, &setup.issuer_doc, options, FailFast::FirstError).unwrap()
+  }
+}